@@ -0,0 +1,64 @@
+//! Conversions between [`Datetime`] and [`chrono::NaiveDateTime`].
+
+use crate::{
+    Component, DateTimeParseError, DateTimeParseErrorKind, Datetime, Day, Hour, HourMinuteSecond,
+    Minute, Month, Second, Separator, Year, YearMonthDay,
+};
+use alloc::string::ToString;
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use core::convert::TryFrom;
+
+impl TryFrom<Datetime> for NaiveDateTime {
+    type Error = DateTimeParseError;
+
+    /// Converts the fractional second to nanoseconds. Fails if the year is outside of chrono's
+    /// supported range.
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        let year = value.date.year.0;
+        let date =
+            NaiveDate::from_ymd_opt(year, value.date.month.0.into(), value.date.day.0.into())
+                .ok_or_else(|| DateTimeParseError {
+                    component: Component::Year,
+                    found: year.to_string(),
+                    kind: DateTimeParseErrorKind::OutOfRange {
+                        min: NaiveDate::MIN.year(),
+                        max: NaiveDate::MAX.year(),
+                    },
+                    position: None,
+                })?;
+
+        let (second, nanos) = value.time.second.whole_and_nanos();
+        let time = NaiveTime::from_hms_nano_opt(
+            value.time.hour.0.into(),
+            value.time.minute.0.into(),
+            second.into(),
+            nanos,
+        )
+        .expect("The components of a Datetime are in range");
+
+        Ok(NaiveDateTime::new(date, time))
+    }
+}
+
+impl TryFrom<NaiveDateTime> for Datetime {
+    type Error = DateTimeParseError;
+
+    /// Fails for years before 1 and for leap seconds, neither of which HTML can represent. The
+    /// nanoseconds are truncated to milliseconds.
+    fn try_from(value: NaiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Datetime {
+            date: YearMonthDay::from_components(
+                Year::try_from(value.year())?,
+                Month(value.month() as u8),
+                Day(value.day() as u8),
+            )?,
+            time: HourMinuteSecond {
+                hour: Hour(value.hour() as u8),
+                minute: Minute(value.minute() as u8),
+                second: Second::from_whole_and_nanos(value.second() as u8, value.nanosecond())?,
+            },
+            separator: Separator::T,
+            expanded_year_digits: None,
+        })
+    }
+}
@@ -32,6 +32,15 @@
 //! }
 //! ```
 //!
+//! ## Optional features
+//!
+//! - `std` (enabled by default): links the standard library and adds `Datetime::now_utc`. Without
+//!   it, the crate is `no_std` and only requires `alloc`. None of the other features depend on it.
+//! - `chrono`: conversions between `Datetime` and `chrono::NaiveDateTime`.
+//! - `serde`: (de)serializes `Datetime` as its string representation, or as a struct of its
+//!   components through the `StructuredDatetime` wrapper.
+//! - `time`: conversions between `Datetime` and `time::PrimitiveDateTime`.
+//!
 //! # Contributing
 //!
 //! Pull requests and bug reports are welcome! If you have any questions or suggestions, feel free to open an issue.
@@ -44,61 +53,681 @@
 //!
 //! Special thanks to [ChatGPT](https://www.openai.com/gpt), an AI language model by OpenAI, for providing invaluable assistance during the development of this project. ChatGPT helped with code suggestions, problem-solving, and provided guidance throughout the development process.
 
-use anyhow::Error;
-use std::convert::TryFrom;
-use std::str::FromStr;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+extern crate std;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter;
+use core::num::{ParseFloatError, ParseIntError};
+use core::ops::{Add, Range, Sub};
+use core::str::{FromStr, Utf8Error};
+use core::time::Duration;
 use thiserror::Error;
 
+#[cfg(feature = "chrono")]
+mod chrono_impl;
+mod scanner;
+#[cfg(feature = "serde")]
+mod serde_impl;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "time")]
+mod time_impl;
 
-#[derive(Debug, PartialEq, Clone)]
+#[cfg(feature = "serde")]
+pub use serde_impl::StructuredDatetime;
+
+use scanner::{ascii_digits, non_empty, time_len, two_digits, year_digits};
+
+/// A local date and time, such as the value of `<input type="datetime-local">`.
+///
+/// Datetimes are totally ordered chronologically, so they can be sorted or used as keys of a
+/// `BTreeMap`. They can also be hashed, e.g. to be used as keys of a `HashMap`.
+#[derive(Debug, Clone)]
 pub struct Datetime {
     pub date: YearMonthDay,
     pub time: HourMinuteSecond,
+    separator: Separator,
+    // The number of year digits as written, if the year had a leading `+`, so that displaying
+    // keeps both the sign and the zero padding. See `DatetimeParser::allow_expanded_year`.
+    expanded_year_digits: Option<u8>,
+}
+
+/// The character between the date and the time of a [`Datetime`].
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Separator {
+    #[default]
+    T,
+    Space,
+}
+
+impl Separator {
+    fn as_char(self) -> char {
+        match self {
+            Separator::T => 'T',
+            Separator::Space => ' ',
+        }
+    }
+}
+
+// The separator and how the year was written are a matter of notation rather than of the value, so
+// they're ignored when comparing datetimes.
+impl PartialEq for Datetime {
+    fn eq(&self, other: &Self) -> bool {
+        self.date == other.date && self.time == other.time
+    }
+}
+
+impl Eq for Datetime {}
+
+impl Hash for Datetime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.date.hash(state);
+        self.time.hash(state);
+    }
+}
+
+impl PartialOrd for Datetime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Datetime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.date, &self.time).cmp(&(&other.date, &other.time))
+    }
+}
+
+/// [`Datetime::UNIX_EPOCH`].
+impl Default for Datetime {
+    fn default() -> Self {
+        Datetime::UNIX_EPOCH
+    }
 }
 
 impl FromStr for Datetime {
     type Err = DateTimeParseError;
 
+    /// Same as [`Datetime::parse_local`].
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split('T');
+        Datetime::parse_local(s)
+    }
+}
+
+/// Same as [`Datetime::from_str`].
+///
+/// ```
+/// use html_datetime_local::Datetime;
+///
+/// let dt = Datetime::try_from("2023-12-18T12:34").unwrap();
+/// assert_eq!(dt, "2023-12-18T12:34".parse().unwrap());
+/// assert!(Datetime::try_from("2023-12-18").is_err());
+/// ```
+impl TryFrom<&str> for Datetime {
+    type Error = DateTimeParseError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+/// Parses datetimes according to a configurable policy.
+///
+/// The default parser follows the HTML specification, just like [`Datetime::from_str`]. The
+/// builder methods relax or tighten it:
+///
+/// ```
+/// use html_datetime_local::DatetimeParser;
+///
+/// let parser = DatetimeParser::new().allow_single_digit_fields(true);
+/// assert_eq!(
+///     parser.parse("2023-1-2T03:04").unwrap(),
+///     "2023-01-02T03:04".parse().unwrap()
+/// );
+/// assert!(DatetimeParser::new().parse("2023-1-2T03:04").is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct DatetimeParser {
+    allow_single_digit_fields: bool,
+    allow_space_separator: bool,
+    require_seconds: bool,
+    allow_end_of_day: bool,
+    allow_comma_decimal: bool,
+    allow_basic_format: bool,
+    allow_expanded_year: bool,
+    trim_input: bool,
+}
+
+impl Default for DatetimeParser {
+    fn default() -> Self {
+        DatetimeParser {
+            allow_single_digit_fields: false,
+            allow_space_separator: true,
+            require_seconds: false,
+            allow_end_of_day: false,
+            allow_comma_decimal: false,
+            allow_basic_format: false,
+            allow_expanded_year: false,
+            trim_input: false,
+        }
+    }
+}
+
+impl DatetimeParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the month, the day, the hour, the minute and the whole seconds may consist of a
+    /// single digit, e.g. `2023-1-2T3:4:5`. Disabled by default.
+    pub fn allow_single_digit_fields(self, allow: bool) -> Self {
+        DatetimeParser {
+            allow_single_digit_fields: allow,
+            ..self
+        }
+    }
+
+    /// Whether the date and the time may be separated by a space instead of a `T`. Enabled by
+    /// default, as in HTML.
+    pub fn allow_space_separator(self, allow: bool) -> Self {
+        DatetimeParser {
+            allow_space_separator: allow,
+            ..self
+        }
+    }
+
+    /// Whether the seconds have to be present. Disabled by default.
+    pub fn require_seconds(self, require: bool) -> Self {
+        DatetimeParser {
+            require_seconds: require,
+            ..self
+        }
+    }
+
+    /// Whether `24:00` may denote the end of the day, which is parsed as midnight of the next day.
+    /// Disabled by default, as HTML doesn't allow hour 24.
+    ///
+    /// ```
+    /// use html_datetime_local::DatetimeParser;
+    ///
+    /// let parser = DatetimeParser::new().allow_end_of_day(true);
+    /// assert_eq!(
+    ///     parser.parse("2023-12-31T24:00:00").unwrap(),
+    ///     "2024-01-01T00:00:00".parse().unwrap()
+    /// );
+    /// ```
+    pub fn allow_end_of_day(self, allow: bool) -> Self {
+        DatetimeParser {
+            allow_end_of_day: allow,
+            ..self
+        }
+    }
+
+    /// Whether the fraction of the second may be separated by a comma, e.g. `12:34:56,5`.
+    /// Disabled by default.
+    pub fn allow_comma_decimal(self, allow: bool) -> Self {
+        DatetimeParser {
+            allow_comma_decimal: allow,
+            ..self
+        }
+    }
+
+    /// Whether input without hyphens and colons may be in the ISO 8601 basic format
+    /// `YYYYMMDDThhmm[ss]`, e.g. `20231218T123456`. Disabled by default.
+    pub fn allow_basic_format(self, allow: bool) -> Self {
+        DatetimeParser {
+            allow_basic_format: allow,
+            ..self
+        }
+    }
+
+    /// Whether the year may have a leading `+`, as in ISO 8601's expanded representation, e.g.
+    /// `+012023-12-18T00:00`. The sign and the number of year digits are kept when displaying the
+    /// result, e.g. as `+012023-12-18T00:00:00`. Disabled by default.
+    /// A `-` is still rejected, as HTML doesn't allow years before 1.
+    pub fn allow_expanded_year(self, allow: bool) -> Self {
+        DatetimeParser {
+            allow_expanded_year: allow,
+            ..self
+        }
+    }
+
+    /// Whether ASCII whitespace around the datetime is ignored. Disabled by default, as HTML
+    /// doesn't allow it. Error positions still refer to the untrimmed input.
+    pub fn trim_input(self, trim: bool) -> Self {
+        DatetimeParser {
+            trim_input: trim,
+            ..self
+        }
+    }
+
+    pub fn parse(&self, s: &str) -> Result<Datetime, DateTimeParseError> {
+        self.parse_borrowed(s)
+            .map_err(BorrowedDateTimeParseError::into_owned)
+    }
+
+    /// Like [`DatetimeParser::parse`], but the error borrows the offending part of `s` instead of
+    /// copying it, so that rejecting invalid input doesn't allocate.
+    ///
+    /// ```
+    /// use html_datetime_local::{Component, DatetimeParser};
+    ///
+    /// let err = DatetimeParser::new()
+    ///     .parse_borrowed("2023-13-01T00:00")
+    ///     .unwrap_err();
+    /// assert_eq!((err.component, err.found), (Component::Month, "13"));
+    /// ```
+    pub fn parse_borrowed<'a>(
+        &self,
+        s: &'a str,
+    ) -> Result<Datetime, BorrowedDateTimeParseError<'a>> {
+        if self.trim_input {
+            let trimmed = s.trim_start_matches(|c: char| c.is_ascii_whitespace());
+            let offset = s.len() - trimmed.len();
+            let trimmed = trimmed.trim_end_matches(|c: char| c.is_ascii_whitespace());
+            return self
+                .trim_input(false)
+                .parse_borrowed(trimmed)
+                .map_err(|err| err.offset_by(offset));
+        }
+
+        if self.allow_basic_format && !s.contains(['-', ':']) {
+            return parse_basic(s);
+        }
 
-        let date = YearMonthDay::from_str(parts.next().ok_or_else(|| DateTimeParseError {
+        // Everything from `position` on is reported, so that `found` starts where `position` points.
+        let unexpected_characters = |position: usize| BorrowedDateTimeParseError {
+            component: Component::Time,
+            found: &s[position..],
+            kind: DateTimeParseErrorKind::UnexpectedCharacters,
+            position: Some(position),
+        };
+
+        let separator = match (s.contains('T'), s.find(' ')) {
+            (true, Some(space)) => return Err(unexpected_characters(space)),
+            (false, Some(space)) if !self.allow_space_separator => {
+                return Err(unexpected_characters(space))
+            }
+            (false, Some(_)) => Separator::Space,
+            _ => Separator::T,
+        };
+        let mut parts = s.splitn(3, separator.as_char());
+
+        let date_part = parts.next().ok_or(BorrowedDateTimeParseError {
             component: Component::Date,
-            found: "".to_string(),
-            kind: DateTimeParseErrorKind::ValueMissing,
-        })?)?;
+            found: "",
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(0),
+        })?;
+        let (expanded_year_digits, mut date) = match date_part.strip_prefix('+') {
+            Some(date) if self.allow_expanded_year => {
+                let digits = date.find('-').unwrap_or(date.len());
+                (
+                    Some(u8::try_from(digits).unwrap_or(u8::MAX)),
+                    self.parse_date(date).map_err(|err| err.offset_by(1))?,
+                )
+            }
+            _ => (None, self.parse_date(date_part)?),
+        };
 
-        let time = HourMinuteSecond::from_str(parts.next().ok_or_else(|| DateTimeParseError {
+        let time_part = parts.next().ok_or(BorrowedDateTimeParseError {
             component: Component::Time,
-            found: "".to_string(),
-            kind: DateTimeParseErrorKind::ValueMissing,
-        })?)?;
+            found: "",
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(s.len()),
+        })?;
+        let time_offset = date_part.len() + 1;
+        let time = if self.allow_end_of_day && time_part.starts_with("24") {
+            self.end_of_day(time_part)
+                .map_err(|err| err.offset_by(time_offset))?;
+            date = date.checked_add_days(1).ok_or(BorrowedDateTimeParseError {
+                component: Component::Year,
+                // The year as written, since the one the date rolls over into isn't in the input.
+                found: date_part.split('-').next().unwrap_or(date_part),
+                kind: DateTimeParseErrorKind::OutOfRange {
+                    min: Year::MIN,
+                    max: Year::MAX,
+                },
+                position: Some(0),
+            })?;
+            HourMinuteSecond::MIDNIGHT
+        } else {
+            self.parse_time(time_part)
+                .map_err(|err| err.offset_by(time_offset))?
+        };
+
+        if parts.next().is_some() {
+            return Err(unexpected_characters(time_offset + time_part.len()));
+        }
+
+        Ok(Datetime {
+            date,
+            time,
+            separator,
+            expanded_year_digits,
+        })
+    }
+
+    // Checks that `time`, whose hour is 24, is the end of the day. Positions are relative to the
+    // start of the time.
+    fn end_of_day<'a>(&self, time: &'a str) -> Result<(), BorrowedDateTimeParseError<'a>> {
+        let out_of_range = |found| BorrowedDateTimeParseError {
+            component: Component::Hour,
+            found,
+            kind: DateTimeParseErrorKind::OutOfRange { min: 0, max: 23 },
+            position: Some(0),
+        };
+
+        // The hour only counts as the start of the next day if it's exactly `24`.
+        let parsed =
+            self.parse_time_with_hour(time, |hour| match two_digits(hour, Component::Hour)? {
+                "24" => Ok(Hour(0)),
+                hour => Err(out_of_range(hour)),
+            })?;
+        if !parsed.is_exactly(0, 0) {
+            return Err(out_of_range(&time[..2]));
+        }
+
+        Ok(())
+    }
+
+    // Checks the digits of a month or a day.
+    fn field_digits<'a>(
+        &self,
+        value: &'a str,
+        component: Component,
+    ) -> Result<&'a str, BorrowedDateTimeParseError<'a>> {
+        if self.allow_single_digit_fields
+            && value.len() == 1
+            && value.as_bytes()[0].is_ascii_digit()
+        {
+            return Ok(value);
+        }
+
+        two_digits(value, component)
+    }
+
+    fn parse_date<'a>(
+        &self,
+        value: &'a str,
+    ) -> Result<YearMonthDay, BorrowedDateTimeParseError<'a>> {
+        let mut parts = value.splitn(4, '-');
+
+        let year = parts.next().ok_or(BorrowedDateTimeParseError {
+            found: "",
+            component: Component::Year,
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(0),
+        })?;
+        let month = parts.next().ok_or(BorrowedDateTimeParseError {
+            found: "",
+            component: Component::Month,
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(value.len()),
+        })?;
+        let day = parts.next().ok_or(BorrowedDateTimeParseError {
+            found: "",
+            component: Component::Day,
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(value.len()),
+        })?;
+        let month_offset = year.len() + 1;
+        let day_offset = month_offset + month.len() + 1;
+        if parts.next().is_some() {
+            let position = day_offset + day.len();
+            return Err(BorrowedDateTimeParseError {
+                found: &value[position..],
+                component: Component::Date,
+                kind: DateTimeParseErrorKind::UnexpectedCharacters,
+                position: Some(position),
+            });
+        }
+
+        let year = non_empty(year, Component::Year)
+            .and_then(year_digits)
+            .and_then(Year::from_str_borrowed)?;
+        let month = non_empty(month, Component::Month)
+            .and_then(|month| self.field_digits(month, Component::Month))
+            .and_then(Month::from_str_borrowed)
+            .map_err(|err| err.offset_by(month_offset))?;
+        let day_digits = day;
+        let day = non_empty(day, Component::Day)
+            .and_then(|day| self.field_digits(day, Component::Day))
+            .and_then(Day::from_str_borrowed)
+            .map_err(|err| err.offset_by(day_offset))?;
+
+        date_with_day_as_written(year, month, day, day_digits, day_offset)
+    }
+
+    fn parse_time<'a>(
+        &self,
+        value: &'a str,
+    ) -> Result<HourMinuteSecond, BorrowedDateTimeParseError<'a>> {
+        self.parse_time_with_hour(value, |hour| {
+            self.field_digits(hour, Component::Hour)
+                .and_then(Hour::from_str_borrowed)
+        })
+    }
+
+    // Parses a time whose hour, once checked for presence, is parsed by `parse_hour`.
+    fn parse_time_with_hour<'a>(
+        &self,
+        value: &'a str,
+        parse_hour: impl FnOnce(&'a str) -> Result<Hour, BorrowedDateTimeParseError<'a>>,
+    ) -> Result<HourMinuteSecond, BorrowedDateTimeParseError<'a>> {
+        let (value, rest) = value.split_at(time_len(value, self.allow_comma_decimal));
+        if !rest.is_empty() {
+            return Err(BorrowedDateTimeParseError {
+                component: Component::Time,
+                found: rest,
+                kind: DateTimeParseErrorKind::UnexpectedCharacters,
+                position: Some(value.len()),
+            });
+        }
+
+        // `time_len` has already made sure there are at most three parts.
+        let mut parts = value.split(':');
+
+        let hour = parts.next().ok_or(BorrowedDateTimeParseError {
+            component: Component::Hour,
+            found: value,
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(0),
+        })?;
+        let minute = parts.next().ok_or(BorrowedDateTimeParseError {
+            component: Component::Minute,
+            found: value,
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(value.len()),
+        })?;
+
+        let minute_offset = hour.len() + 1;
+        let second_offset = minute_offset + minute.len() + 1;
+        let hour = non_empty(hour, Component::Hour).and_then(parse_hour)?;
+        let minute = non_empty(minute, Component::Minute)
+            .and_then(|minute| self.field_digits(minute, Component::Minute))
+            .and_then(Minute::from_str_borrowed)
+            .map_err(|err| err.offset_by(minute_offset))?;
+        let second = match parts.next() {
+            Some(second) => non_empty(second, Component::Second)
+                .and_then(|second| self.parse_second(second))
+                .map_err(|err| err.offset_by(second_offset))?,
+            None if self.require_seconds => {
+                return Err(BorrowedDateTimeParseError {
+                    component: Component::Second,
+                    found: "",
+                    kind: DateTimeParseErrorKind::Missing,
+                    position: Some(value.len()),
+                })
+            }
+            None => Second::UNSPECIFIED,
+        };
+
+        Ok(HourMinuteSecond {
+            hour,
+            minute,
+            second,
+        })
+    }
 
-        Ok(Datetime { date, time })
+    // Parses a second, whose whole part has the same number of digits as the other fields.
+    fn parse_second<'a>(&self, value: &'a str) -> Result<Second, BorrowedDateTimeParseError<'a>> {
+        let whole_len = value
+            .find(|c| c == '.' || self.allow_comma_decimal && c == ',')
+            .unwrap_or(value.len());
+        self.field_digits(&value[..whole_len], Component::Second)?;
+        Second::from_str_borrowed(value, self.allow_comma_decimal)
     }
 }
 
-#[derive(Debug, Error)]
-#[error("Failed to parse {component}'s value `{found}`: {kind}")]
+/// Describes which component of the input couldn't be parsed and why.
+///
+/// ```
+/// use html_datetime_local::{Component, DateTimeParseError, DateTimeParseErrorKind, Datetime};
+///
+/// let err = "2023-15-01T01:01:01".parse::<Datetime>().unwrap_err();
+/// assert!(matches!(
+///     err,
+///     DateTimeParseError {
+///         component: Component::Month,
+///         kind: DateTimeParseErrorKind::OutOfRange { .. },
+///         ..
+///     } if err.found == "15" && err.position == Some(5)
+/// ));
+/// ```
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("Failed to parse the {component} component, found `{found}`: {kind}")]
 pub struct DateTimeParseError {
-    component: Component,
-    found: String,
-    kind: DateTimeParseErrorKind,
+    pub component: Component,
+    pub found: String,
+    #[source]
+    pub kind: DateTimeParseErrorKind,
+    /// The byte offset in the input at which the offending part starts, if the error came from
+    /// parsing a string.
+    pub position: Option<usize>,
+}
+
+impl DateTimeParseError {
+    /// Returns the component that couldn't be parsed.
+    pub fn component(&self) -> Component {
+        self.component
+    }
+
+    /// Whether the component was a valid number, but outside of the allowed range.
+    pub fn is_out_of_range(&self) -> bool {
+        matches!(self.kind, DateTimeParseErrorKind::OutOfRange { .. })
+    }
+
+    /// Whether the component wasn't a valid number.
+    pub fn is_invalid_number(&self) -> bool {
+        matches!(self.kind, DateTimeParseErrorKind::InvalidNumber(_))
+    }
+
+    /// Whether the component was missing from the input.
+    pub fn is_missing_component(&self) -> bool {
+        matches!(self.kind, DateTimeParseErrorKind::Missing)
+    }
+
+    // Adjusts the position of an error from parsing a part of the input that starts at `offset`.
+    fn offset_by(self, offset: usize) -> Self {
+        DateTimeParseError {
+            position: self.position.map(|position| position + offset),
+            ..self
+        }
+    }
+}
+
+/// Like [`DateTimeParseError`], but borrows the offending part of the input instead of owning a
+/// copy of it. Returned by [`DatetimeParser::parse_borrowed`].
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+#[error("Failed to parse the {component} component, found `{found}`: {kind}")]
+pub struct BorrowedDateTimeParseError<'a> {
+    pub component: Component,
+    pub found: &'a str,
+    #[source]
+    pub kind: DateTimeParseErrorKind,
+    /// The byte offset in the input at which the offending part starts.
+    pub position: Option<usize>,
+}
+
+impl BorrowedDateTimeParseError<'_> {
+    /// Copies the offending part of the input, so that the error no longer borrows it.
+    pub fn into_owned(self) -> DateTimeParseError {
+        DateTimeParseError {
+            component: self.component,
+            found: self.found.to_string(),
+            kind: self.kind,
+            position: self.position,
+        }
+    }
+
+    fn offset_by(self, offset: usize) -> Self {
+        BorrowedDateTimeParseError {
+            position: self.position.map(|position| position + offset),
+            ..self
+        }
+    }
+}
+
+impl From<BorrowedDateTimeParseError<'_>> for DateTimeParseError {
+    fn from(err: BorrowedDateTimeParseError<'_>) -> Self {
+        err.into_owned()
+    }
 }
 
-#[derive(Debug, Error)]
+/// Why a component couldn't be parsed. Also the [`source`](core::error::Error::source) of a
+/// [`DateTimeParseError`], so that for an invalid number, e.g. the `ParseIntError` can be reached
+/// by walking the chain of sources.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
 pub enum DateTimeParseErrorKind {
-    #[error(transparent)]
-    InvalidNumber(Error),
+    #[error("The value is not a valid number")]
+    InvalidNumber(#[source] NumberError),
     #[error("The value is missing")]
-    ValueMissing,
+    Missing,
     #[error("The value must be at least {min} and at most {max}")]
     OutOfRange { min: i32, max: i32 },
+    #[error("Unexpected characters")]
+    UnexpectedCharacters,
+    #[error("The format specifier is not supported")]
+    UnsupportedSpecifier,
+}
+
+/// Why the characters of a component don't form a valid number.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum NumberError {
+    // The underlying error is the source, so it's left out of the message to avoid printing it
+    // twice when the chain of sources is reported.
+    #[error("The digits don't form a valid integer")]
+    Int(#[from] ParseIntError),
+    #[error("The digits don't form a valid decimal number")]
+    Float(#[from] ParseFloatError),
+    #[error("The value is not valid UTF-8")]
+    Utf8(#[from] Utf8Error),
+    #[error("The value must consist of ASCII digits only")]
+    NotAsciiDigits,
+    #[error("The value must consist of exactly two digits")]
+    NotTwoDigits,
+    #[error("The value must consist of at least four digits")]
+    TooFewYearDigits,
+    #[error("The fractional part must consist of one to three digits")]
+    InvalidFraction,
+    #[error("The value must be finite")]
+    NotFinite,
 }
 
-#[derive(Debug, PartialEq, Clone, strum::Display)]
+/// The part of the input that an error refers to. Displayed in lowercase, e.g. `month`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, strum::Display, strum::IntoStaticStr)]
+#[strum(serialize_all = "lowercase")]
 pub enum Component {
     Year,
     Month,
@@ -106,37 +735,86 @@ pub enum Component {
     Hour,
     Minute,
     Second,
+    Week,
+    Offset,
 
     Date,
     Time,
+    Format,
+}
+
+impl Component {
+    /// Returns the lowercase name of the component, as used in error messages.
+    pub fn name(&self) -> &'static str {
+        self.into()
+    }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+/// A year of the proleptic Gregorian calendar in the range `Year::MIN..=Year::MAX`. HTML doesn't
+/// allow years before 1, and the range fits in an `i32` with room to spare.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Year(i32);
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Month(u8);
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Day(u8);
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Hour(u8);
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
 pub struct Minute(u8);
 
-#[derive(Debug, PartialEq, PartialOrd, Clone, Copy)]
-pub struct Second(f32);
+/// A second of a minute with millisecond precision, which is the most HTML allows.
+///
+/// The number of fractional digits a value was parsed with is kept for formatting, but doesn't
+/// take part in comparisons: `56.50` is equal to `56.5`. The same goes for whether the second was
+/// written at all: `12:34` has a second of zero, just like `12:34:00`.
+#[derive(Debug, Clone, Copy)]
+pub struct Second {
+    millis: u16,
+    // At least as many as the value needs, e.g. 2 for `56.50`.
+    fractional_digits: u8,
+    // False if the time was written without seconds, see `Datetime::compare_same_precision`.
+    specified: bool,
+}
+
+impl PartialEq for Second {
+    fn eq(&self, other: &Self) -> bool {
+        self.millis == other.millis
+    }
+}
+
+impl Eq for Second {}
+
+impl PartialOrd for Second {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Second {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.millis.cmp(&other.millis)
+    }
+}
+
+impl Hash for Second {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.millis.hash(state);
+    }
+}
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct YearMonthDay {
     year: Year,
     month: Month,
     day: Day,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
 pub struct HourMinuteSecond {
     hour: Hour,
     minute: Minute,
@@ -145,18 +823,58 @@ pub struct HourMinuteSecond {
 
 macro_rules! impl_parse_numeric {
     ($component:tt, $inner:ty, $min:expr, $max:expr) => {
+        impl $component {
+            /// Like `try_from`, but usable in constants.
+            // The comparison with the minimum of 0 is useless for unsigned components.
+            #[allow(unused_comparisons)]
+            pub const fn new(value: $inner) -> Option<Self> {
+                if value < $min || value > $max {
+                    return None;
+                }
+
+                Some(Self(value))
+            }
+
+            /// Clamps `value` to the valid range instead of rejecting it like `try_from` does.
+            pub fn saturating_from(value: $inner) -> Self {
+                Self(value.clamp($min, $max))
+            }
+
+            // Parses `value` without allocating, reporting errors with the text as written.
+            fn from_str_borrowed(value: &str) -> Result<Self, BorrowedDateTimeParseError<'_>> {
+                let error = |kind| BorrowedDateTimeParseError {
+                    component: Component::$component,
+                    found: value,
+                    kind,
+                    position: Some(0),
+                };
+
+                ascii_digits(value, Component::$component)?;
+                let inner = <$inner as FromStr>::from_str(value).map_err(|source| {
+                    error(DateTimeParseErrorKind::InvalidNumber(source.into()))
+                })?;
+                Self::new(inner).ok_or_else(|| {
+                    error(DateTimeParseErrorKind::OutOfRange {
+                        min: $min as i32,
+                        max: $max as i32,
+                    })
+                })
+            }
+        }
+
         impl TryFrom<$inner> for $component {
             type Error = DateTimeParseError;
 
             fn try_from(value: $inner) -> Result<Self, Self::Error> {
-                if !(($min as $inner)..=($max as $inner)).contains(&value) {
+                if !($min..=$max).contains(&value) {
                     return Err(DateTimeParseError {
                         component: Component::$component,
                         found: value.to_string(),
                         kind: DateTimeParseErrorKind::OutOfRange {
-                            min: $min,
-                            max: ($max - 1),
+                            min: $min as i32,
+                            max: $max as i32,
                         },
+                        position: None,
                     });
                 }
 
@@ -168,117 +886,2342 @@ macro_rules! impl_parse_numeric {
             type Err = DateTimeParseError;
 
             fn from_str(value: &str) -> Result<Self, Self::Err> {
-                let inner =
-                    <$inner as FromStr>::from_str(value).map_err(|source| DateTimeParseError {
-                        component: Component::$component,
-                        found: value.to_string(),
-                        kind: DateTimeParseErrorKind::InvalidNumber(source.into()),
-                    })?;
-
-                Self::try_from(inner)
+                Ok(Self::from_str_borrowed(value)?)
             }
         }
     };
 }
 
-impl_parse_numeric!(Year, i32, i32::MIN, i32::MAX);
-impl_parse_numeric!(Month, u8, 1, 13);
-impl_parse_numeric!(Day, u8, 1, 32);
-impl_parse_numeric!(Hour, u8, 0, 24);
-impl_parse_numeric!(Minute, u8, 0, 60);
-impl_parse_numeric!(Second, f32, 0, 60);
-
-impl FromStr for YearMonthDay {
-    type Err = DateTimeParseError;
-
-    fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = value.split('-').collect();
+impl_parse_numeric!(Year, i32, Year::MIN, Year::MAX);
+impl_parse_numeric!(Month, u8, 1, 12);
+impl_parse_numeric!(Day, u8, 1, 31);
+impl_parse_numeric!(Hour, u8, 0, 23);
+impl_parse_numeric!(Minute, u8, 0, 59);
 
-        let year = parts.first().ok_or_else(|| DateTimeParseError {
-            found: "".to_string(),
-            component: Component::Year,
-            kind: DateTimeParseErrorKind::ValueMissing,
-        })?;
-        let month = parts.get(1).ok_or_else(|| DateTimeParseError {
-            found: "".to_string(),
-            component: Component::Month,
-            kind: DateTimeParseErrorKind::ValueMissing,
-        })?;
-        let day = parts.get(2).ok_or_else(|| DateTimeParseError {
-            found: "".to_string(),
-            component: Component::Day,
-            kind: DateTimeParseErrorKind::ValueMissing,
-        })?;
+const MILLIS_PER_MINUTE: u32 = 60_000;
 
-        let year = Year::from_str(year)?;
-        let month = Month::from_str(month)?;
-        let day = Day::from_str(day)?;
+impl Second {
+    // Creates a value with as few fractional digits as `millis` needs.
+    const fn new(millis: u16) -> Self {
+        let mut fraction = millis % 1000;
+        let mut fractional_digits = 0;
+        if fraction != 0 {
+            fractional_digits = 3;
+            while fraction.is_multiple_of(10) {
+                fraction /= 10;
+                fractional_digits -= 1;
+            }
+        }
 
-        Self::from_components(year, month, day)
+        Second {
+            millis,
+            fractional_digits,
+            specified: true,
+        }
     }
-}
 
-impl YearMonthDay {
-    pub fn from_components(year: Year, month: Month, day: Day) -> Result<Self, DateTimeParseError> {
-        if !is_valid_day(year, month, day) {
+    // The second of a time written without one.
+    const UNSPECIFIED: Second = Second {
+        specified: false,
+        ..Second::new(0)
+    };
+
+    fn from_millis(millis: u32) -> Result<Self, DateTimeParseError> {
+        if millis >= MILLIS_PER_MINUTE {
             return Err(DateTimeParseError {
-                kind: DateTimeParseErrorKind::OutOfRange {
-                    min: 1,
-                    max: day_in_month(year, month) as i32,
-                },
-                found: day.0.to_string(),
-                component: Component::Day,
+                component: Component::Second,
+                found: format!("{}.{:03}", millis / 1000, millis % 1000),
+                kind: DateTimeParseErrorKind::OutOfRange { min: 0, max: 59 },
+                position: None,
             });
         }
 
-        Ok(YearMonthDay { year, month, day })
+        Ok(Self::new(millis as u16))
     }
-}
 
-// Helper function to check if the given day is valid for the given year and month.
-fn is_valid_day(year: Year, month: Month, day: Day) -> bool {
-    day.0 <= day_in_month(year, month)
+    /// Returns the value in seconds. Not every number of milliseconds is exactly representable
+    /// as an `f32`.
+    pub fn as_f32(&self) -> f32 {
+        f32::from(self.millis) / 1000.0
+    }
+
+    /// Returns the number of digits after the decimal point, as parsed. Values that weren't
+    /// parsed have as few as they need, e.g. none for a whole second.
+    pub fn fractional_digits(&self) -> u8 {
+        self.fractional_digits
+    }
+
+    fn whole(self) -> u8 {
+        (self.millis / 1000) as u8
+    }
+
+    fn millis(self) -> u32 {
+        u32::from(self.millis)
+    }
 }
 
-// Helper function to determine the number of days in a month.
-fn day_in_month(year: Year, month: Month) -> u8 {
-    match month.0 {
-        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
-        4 | 6 | 9 | 11 => 30,
-        2 if is_leap_year(year.0) => 29,
-        2 => 28,
-        _ => unreachable!("The Month type guards against values that aren't in range (1..=12)"),
+impl TryFrom<f32> for Second {
+    type Error = DateTimeParseError;
+
+    /// Rounds `value` to the nearest millisecond.
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        let error = |kind| DateTimeParseError {
+            component: Component::Second,
+            found: value.to_string(),
+            kind,
+            position: None,
+        };
+
+        if !value.is_finite() {
+            return Err(error(DateTimeParseErrorKind::InvalidNumber(
+                NumberError::NotFinite,
+            )));
+        }
+        // Checking the range first also keeps the conversion to milliseconds from saturating.
+        if !(0.0..60.0).contains(&value) {
+            return Err(error(DateTimeParseErrorKind::OutOfRange {
+                min: 0,
+                max: 59,
+            }));
+        }
+
+        let millis = round_non_negative(f64::from(value) * 1000.0) as u32;
+        Self::from_millis(millis).map_err(|err| DateTimeParseError {
+            found: value.to_string(),
+            ..err
+        })
     }
 }
 
-// Helper function to check if a year is a leap year.
-fn is_leap_year(year: i32) -> bool {
-    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+impl Second {
+    // Parses `value` without allocating. A comma is accepted as the decimal separator if
+    // `allow_comma_decimal` is set.
+    fn from_str_borrowed(
+        value: &str,
+        allow_comma_decimal: bool,
+    ) -> Result<Self, BorrowedDateTimeParseError<'_>> {
+        let error = |kind| BorrowedDateTimeParseError {
+            component: Component::Second,
+            found: value,
+            kind,
+            position: Some(0),
+        };
+        let invalid_number =
+            |source: NumberError| error(DateTimeParseErrorKind::InvalidNumber(source));
+
+        let is_decimal = |c| c == '.' || allow_comma_decimal && c == ',';
+        let (whole, fraction) = value.split_once(is_decimal).unwrap_or((value, ""));
+        let fractional_digits = fraction.len() as u8;
+        if value.contains(is_decimal) && !(1..=3).contains(&fraction.len())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+        {
+            return Err(invalid_number(NumberError::InvalidFraction));
+        }
+
+        ascii_digits(whole, Component::Second)?;
+        let whole = u32::from_str(whole).map_err(|source| invalid_number(source.into()))?;
+        // Scale the fraction to milliseconds, e.g. "5" is 500 and "05" is 50.
+        let fraction = fraction
+            .bytes()
+            .chain(iter::repeat(b'0'))
+            .take(3)
+            .fold(0, |millis, digit| millis * 10 + u32::from(digit - b'0'));
+
+        let millis = whole.saturating_mul(1000).saturating_add(fraction);
+        if millis >= MILLIS_PER_MINUTE {
+            return Err(error(DateTimeParseErrorKind::OutOfRange {
+                min: 0,
+                max: 59,
+            }));
+        }
+
+        Ok(Second {
+            fractional_digits,
+            ..Self::new(millis as u16)
+        })
+    }
 }
 
-impl FromStr for HourMinuteSecond {
+impl FromStr for Second {
     type Err = DateTimeParseError;
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
-        let parts: Vec<&str> = value.split(':').collect();
+        Ok(Self::from_str_borrowed(value, false)?)
+    }
+}
 
-        let hour = parts.first().ok_or_else(|| DateTimeParseError {
-            component: Component::Hour,
-            found: value.to_string(),
-            kind: DateTimeParseErrorKind::ValueMissing,
-        })?;
-        let minute = parts.get(1).ok_or_else(|| DateTimeParseError {
-            component: Component::Minute,
-            found: value.to_string(),
-            kind: DateTimeParseErrorKind::ValueMissing,
-        })?;
+impl FromStr for YearMonthDay {
+    type Err = DateTimeParseError;
 
-        let second = parts.get(2).unwrap_or(&"0");
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(DatetimeParser::new().parse_date(value)?)
+    }
+}
 
-        Ok(HourMinuteSecond {
-            hour: Hour::from_str(hour)?,
-            minute: Minute::from_str(minute)?,
-            second: Second::from_str(second)?,
+impl TryFrom<&str> for YearMonthDay {
+    type Error = DateTimeParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+// Parses the ISO 8601 basic format `YYYYMMDDThhmm[ss]`, whose fields have fixed widths.
+fn parse_basic(s: &str) -> Result<Datetime, BorrowedDateTimeParseError<'_>> {
+    let malformed = |component, found, position| BorrowedDateTimeParseError {
+        component,
+        found,
+        kind: DateTimeParseErrorKind::UnexpectedCharacters,
+        position: Some(position),
+    };
+
+    let (date, time) = s.split_once('T').ok_or(BorrowedDateTimeParseError {
+        component: Component::Time,
+        found: "",
+        kind: DateTimeParseErrorKind::Missing,
+        position: Some(s.len()),
+    })?;
+    let time_offset = date.len() + 1;
+    if !matches!(scanner::split_digits(date, 8), (digits, "") if digits.len() == 8) {
+        return Err(malformed(Component::Date, date, 0));
+    }
+    if !matches!(scanner::split_digits(time, 6), (digits, "") if matches!(digits.len(), 4 | 6)) {
+        return Err(malformed(Component::Time, time, time_offset));
+    }
+
+    let year = Year::from_str_borrowed(&date[..4])?;
+    let month = Month::from_str_borrowed(&date[4..6]).map_err(|err| err.offset_by(4))?;
+    let day = Day::from_str_borrowed(&date[6..]).map_err(|err| err.offset_by(6))?;
+    let date = date_with_day_as_written(year, month, day, &date[6..], 6)?;
+
+    let hour = Hour::from_str_borrowed(&time[..2]).map_err(|err| err.offset_by(time_offset))?;
+    let minute =
+        Minute::from_str_borrowed(&time[2..4]).map_err(|err| err.offset_by(time_offset + 2))?;
+    let second = match &time[4..] {
+        "" => Second::UNSPECIFIED,
+        second => Second::from_str_borrowed(second, false)
+            .map_err(|err| err.offset_by(time_offset + 4))?,
+    };
+
+    Ok(Datetime {
+        date,
+        time: HourMinuteSecond::from_components(hour, minute, second),
+        separator: Separator::T,
+        expanded_year_digits: None,
+    })
+}
+
+// Like `YearMonthDay::from_components`, but reports a day that doesn't exist in the month as
+// `day_digits`, which starts at `position`.
+fn date_with_day_as_written(
+    year: Year,
+    month: Month,
+    day: Day,
+    day_digits: &str,
+    position: usize,
+) -> Result<YearMonthDay, BorrowedDateTimeParseError<'_>> {
+    if !is_valid_day(year, month, day) {
+        return Err(BorrowedDateTimeParseError {
+            component: Component::Day,
+            found: day_digits,
+            kind: DateTimeParseErrorKind::OutOfRange {
+                min: 1,
+                max: days_in_month(year, month) as i32,
+            },
+            position: Some(position),
+        });
+    }
+
+    Ok(YearMonthDay { year, month, day })
+}
+
+impl Datetime {
+    /// The earliest valid datetime, `0001-01-01T00:00:00`.
+    pub const MIN: Datetime = Datetime {
+        date: YearMonthDay {
+            year: Year(1),
+            month: Month(1),
+            day: Day(1),
+        },
+        time: HourMinuteSecond::MIDNIGHT,
+        separator: Separator::T,
+        expanded_year_digits: None,
+    };
+
+    /// The start of the Unix epoch, `1970-01-01T00:00:00`, which is also the default value.
+    pub const UNIX_EPOCH: Datetime = Datetime {
+        date: YearMonthDay {
+            year: Year(1970),
+            month: Month(1),
+            day: Day(1),
+        },
+        time: HourMinuteSecond::MIDNIGHT,
+        separator: Separator::T,
+        expanded_year_digits: None,
+    };
+
+    /// The latest valid datetime, `275760-12-31T23:59:59.999`, i.e. the end of [`Year::MAX`], which
+    /// explains the cap. Seconds with more than three fractional digits can't be parsed, so no
+    /// parsed value exceeds this.
+    pub const MAX: Datetime = Datetime {
+        date: YearMonthDay {
+            year: Year(Year::MAX),
+            month: Month(12),
+            day: Day(31),
+        },
+        time: HourMinuteSecond {
+            hour: Hour(23),
+            minute: Minute(59),
+            second: Second::new(59_999),
+        },
+        separator: Separator::T,
+        expanded_year_digits: None,
+    };
+
+    /// Builds a datetime from its components, checking each of them the same way parsing does.
+    ///
+    /// ```
+    /// use html_datetime_local::{Component, Datetime};
+    ///
+    /// let dt = Datetime::new(2024, 2, 29, 13, 45, 30.5).unwrap();
+    /// assert_eq!(dt.to_string(), "2024-02-29T13:45:30.5");
+    ///
+    /// let err = Datetime::new(2023, 2, 29, 13, 45, 0.0).unwrap_err();
+    /// assert_eq!(err.component, Component::Day);
+    /// ```
+    pub fn new(
+        year: i32,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: f32,
+    ) -> Result<Datetime, DateTimeParseError> {
+        Ok(Datetime {
+            date: YearMonthDay::from_components(
+                Year::try_from(year)?,
+                Month::try_from(month)?,
+                Day::try_from(day)?,
+            )?,
+            time: HourMinuteSecond::from_components(
+                Hour::try_from(hour)?,
+                Minute::try_from(minute)?,
+                Second::try_from(second)?,
+            ),
+            separator: Separator::T,
+            expanded_year_digits: None,
+        })
+    }
+
+    /// Parses a local date and time string as defined by HTML, i.e. the value of an
+    /// `<input type="datetime-local">`. See [`DatetimeParser`] for accepting other inputs as well.
+    ///
+    /// ```
+    /// use html_datetime_local::Datetime;
+    ///
+    /// let dt = Datetime::parse_local("2023-12-18 12:34").unwrap();
+    /// assert_eq!(dt, "2023-12-18T12:34".parse().unwrap());
+    /// assert!(Datetime::parse_local("2023-12-18").is_err());
+    /// ```
+    pub fn parse_local(s: &str) -> Result<Datetime, DateTimeParseError> {
+        DatetimeParser::new().parse(s)
+    }
+
+    /// Like [`Datetime::parse_local`], but reports every invalid component instead of just the
+    /// first one. Input that isn't shaped like a datetime fails with a single error.
+    ///
+    /// ```
+    /// use html_datetime_local::{Component, Datetime};
+    ///
+    /// let errors = Datetime::parse_collecting_errors("2023-13-01T12:60").unwrap_err();
+    /// let components: Vec<_> = errors.iter().map(|err| &err.component).collect();
+    /// assert_eq!(components, [&Component::Month, &Component::Minute]);
+    /// ```
+    pub fn parse_collecting_errors(s: &str) -> Result<Datetime, Vec<DateTimeParseError>> {
+        fn ok<T, E: Into<DateTimeParseError>>(
+            result: Result<T, E>,
+            errors: &mut Vec<DateTimeParseError>,
+        ) -> Option<T> {
+            result.map_err(|err| errors.push(err.into())).ok()
+        }
+
+        let first_error = match Datetime::parse_local(s) {
+            Ok(datetime) => return Ok(datetime),
+            Err(err) => err,
+        };
+        let mut errors = Vec::new();
+
+        let Some((date, time)) = s.split_once(['T', ' ']) else {
+            errors.push(first_error);
+            return Err(errors);
+        };
+        let date_parts: Vec<&str> = date.split('-').collect();
+        let time_parts: Vec<&str> = time.split(':').collect();
+        let (&[year, month, day], &[hour, minute, ref second @ ..]) =
+            (&date_parts[..], &time_parts[..])
+        else {
+            errors.push(first_error);
+            return Err(errors);
+        };
+        if matches!(first_error.component, Component::Date | Component::Time) || second.len() > 1 {
+            errors.push(first_error);
+            return Err(errors);
+        }
+
+        let month_offset = year.len() + 1;
+        let day_offset = month_offset + month.len() + 1;
+        let year = ok(
+            non_empty(year, Component::Year)
+                .and_then(year_digits)
+                .and_then(Year::from_str_borrowed),
+            &mut errors,
+        );
+        let month = ok(
+            non_empty(month, Component::Month)
+                .and_then(|month| two_digits(month, Component::Month))
+                .and_then(Month::from_str_borrowed)
+                .map_err(|err| err.offset_by(month_offset)),
+            &mut errors,
+        );
+        let day = ok(
+            non_empty(day, Component::Day)
+                .and_then(|day| two_digits(day, Component::Day))
+                .and_then(Day::from_str_borrowed)
+                .map_err(|err| err.offset_by(day_offset)),
+            &mut errors,
+        );
+        if let (Some(year), Some(month), Some(day)) = (year, month, day) {
+            ok(
+                YearMonthDay::from_components(year, month, day).map_err(|err| DateTimeParseError {
+                    position: Some(day_offset),
+                    ..err
+                }),
+                &mut errors,
+            );
+        }
+
+        let hour_offset = date.len() + 1;
+        let minute_offset = hour_offset + hour.len() + 1;
+        let second_offset = minute_offset + minute.len() + 1;
+        ok(
+            non_empty(hour, Component::Hour)
+                .and_then(|hour| two_digits(hour, Component::Hour))
+                .and_then(Hour::from_str_borrowed)
+                .map_err(|err| err.offset_by(hour_offset)),
+            &mut errors,
+        );
+        ok(
+            non_empty(minute, Component::Minute)
+                .and_then(|minute| two_digits(minute, Component::Minute))
+                .and_then(Minute::from_str_borrowed)
+                .map_err(|err| err.offset_by(minute_offset)),
+            &mut errors,
+        );
+        if let [second] = second {
+            ok(
+                non_empty(second, Component::Second)
+                    .and_then(|second| DatetimeParser::new().parse_second(second))
+                    .map_err(|err| err.offset_by(second_offset)),
+                &mut errors,
+            );
+        }
+
+        if errors.is_empty() {
+            errors.push(first_error);
+        }
+        Err(errors)
+    }
+
+    /// Parses the value of a form field that may have been left empty, returning `None` for an
+    /// empty or all-whitespace `s`. Like [`DatetimeParser::trim_input`], only ASCII whitespace
+    /// counts. Whitespace around a datetime is still an error.
+    pub fn parse_optional(s: &str) -> Result<Option<Datetime>, DateTimeParseError> {
+        if s.trim_matches(|c: char| c.is_ascii_whitespace()).is_empty() {
+            return Ok(None);
+        }
+
+        Datetime::parse_local(s).map(Some)
+    }
+
+    /// Checks whether `s` is a valid local date and time string, failing with the same error as
+    /// [`Datetime::parse_local`]. Valid input is checked without allocating.
+    pub fn validate(s: &str) -> Result<(), DateTimeParseError> {
+        Datetime::parse_local(s).map(drop)
+    }
+
+    /// Parses a date such as `2023-12-18` as midnight of that day. Unlike
+    /// [`Datetime::parse_local`], this rejects input with a time.
+    pub fn from_date_str_defaulting_midnight(s: &str) -> Result<Datetime, DateTimeParseError> {
+        Ok(Datetime {
+            date: YearMonthDay::from_str(s)?,
+            time: HourMinuteSecond::MIDNIGHT,
+            separator: Separator::T,
+            expanded_year_digits: None,
+        })
+    }
+
+    /// Returns the date part.
+    ///
+    /// ```
+    /// use html_datetime_local::Datetime;
+    ///
+    /// let dt: Datetime = "2023-12-18T09:05:07.25".parse().unwrap();
+    /// assert_eq!(
+    ///     (dt.date().year(), dt.date().month(), dt.date().day()),
+    ///     (2023, 12, 18)
+    /// );
+    /// assert_eq!(
+    ///     (dt.time().hour(), dt.time().minute(), dt.time().second()),
+    ///     (9, 5, 7.25)
+    /// );
+    /// ```
+    pub fn date(&self) -> &YearMonthDay {
+        &self.date
+    }
+
+    /// Returns the time part.
+    pub fn time(&self) -> &HourMinuteSecond {
+        &self.time
+    }
+
+    /// Combines a date and a time.
+    pub fn from_parts(date: YearMonthDay, time: HourMinuteSecond) -> Datetime {
+        Datetime {
+            date,
+            time,
+            separator: Separator::T,
+            expanded_year_digits: None,
+        }
+    }
+
+    /// Splits the datetime into its date and time, the inverse of [`Datetime::from_parts`].
+    pub fn into_parts(self) -> (YearMonthDay, HourMinuteSecond) {
+        (self.date, self.time)
+    }
+
+    /// Returns a copy with the time replaced.
+    pub fn with_time(&self, time: HourMinuteSecond) -> Datetime {
+        Datetime {
+            time,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy with the date replaced.
+    pub fn with_date(&self, date: YearMonthDay) -> Datetime {
+        Datetime {
+            date,
+            ..self.clone()
+        }
+    }
+
+    /// Returns a copy with the year replaced. Fails if the year is invalid or if the day doesn't
+    /// exist in it, i.e. for February 29 in a common year.
+    pub fn with_year(&self, year: i32) -> Result<Datetime, DateTimeParseError> {
+        let date =
+            YearMonthDay::from_components(Year::try_from(year)?, self.date.month, self.date.day)?;
+        Ok(self.with_date(date))
+    }
+
+    /// Returns a copy with the month replaced. Fails if the month is invalid or if the day doesn't
+    /// exist in it, e.g. when changing January 31 to February.
+    ///
+    /// ```
+    /// use html_datetime_local::{Component, Datetime};
+    ///
+    /// let dt: Datetime = "2023-01-31T12:00".parse().unwrap();
+    /// assert_eq!(dt.with_month(3).unwrap().to_string(), "2023-03-31T12:00:00");
+    /// assert_eq!(dt.with_month(2).unwrap_err().component, Component::Day);
+    /// ```
+    pub fn with_month(&self, month: u8) -> Result<Datetime, DateTimeParseError> {
+        let date =
+            YearMonthDay::from_components(self.date.year, Month::try_from(month)?, self.date.day)?;
+        Ok(self.with_date(date))
+    }
+
+    /// Replaces the year in place. On failure, `self` is left unchanged, see
+    /// [`Datetime::with_year`].
+    pub fn set_year(&mut self, year: i32) -> Result<(), DateTimeParseError> {
+        *self = self.with_year(year)?;
+        Ok(())
+    }
+
+    /// Replaces the month in place. On failure, `self` is left unchanged, see
+    /// [`Datetime::with_month`].
+    pub fn set_month(&mut self, month: u8) -> Result<(), DateTimeParseError> {
+        *self = self.with_month(month)?;
+        Ok(())
+    }
+
+    /// Replaces the day in place. Fails without changing `self` if the day doesn't exist in the
+    /// current month and year.
+    ///
+    /// ```
+    /// use html_datetime_local::Datetime;
+    ///
+    /// let mut dt: Datetime = "2024-02-10T12:00".parse().unwrap();
+    /// dt.set_day(29).unwrap();
+    /// assert!(dt.set_day(30).is_err());
+    /// assert_eq!(dt.to_string(), "2024-02-29T12:00:00");
+    /// ```
+    pub fn set_day(&mut self, day: u8) -> Result<(), DateTimeParseError> {
+        self.date =
+            YearMonthDay::from_components(self.date.year, self.date.month, Day::try_from(day)?)?;
+        Ok(())
+    }
+
+    /// Replaces the hour in place. Fails without changing `self` if `hour` is out of range.
+    pub fn set_hour(&mut self, hour: u8) -> Result<(), DateTimeParseError> {
+        self.time.hour = Hour::try_from(hour)?;
+        Ok(())
+    }
+
+    /// Replaces the minute in place. Fails without changing `self` if `minute` is out of range.
+    pub fn set_minute(&mut self, minute: u8) -> Result<(), DateTimeParseError> {
+        self.time.minute = Minute::try_from(minute)?;
+        Ok(())
+    }
+
+    /// Replaces the second in place, rounded to the nearest millisecond. Fails without changing
+    /// `self` if `second` is out of range.
+    pub fn set_second(&mut self, second: f32) -> Result<(), DateTimeParseError> {
+        self.time.second = Second::try_from(second)?;
+        Ok(())
+    }
+
+    /// Compares the datetimes at the precision they were both written with: if either of them
+    /// was written without seconds, e.g. `2023-12-18T12:34`, the seconds are ignored.
+    ///
+    /// ```
+    /// use html_datetime_local::Datetime;
+    /// use std::cmp::Ordering;
+    ///
+    /// let minute: Datetime = "2023-12-18T12:34".parse().unwrap();
+    /// let second: Datetime = "2023-12-18T12:34:56".parse().unwrap();
+    /// assert_eq!(minute.cmp(&second), Ordering::Less);
+    /// assert_eq!(minute.compare_same_precision(&second), Ordering::Equal);
+    /// ```
+    pub fn compare_same_precision(&self, other: &Datetime) -> Ordering {
+        if self.time.second.specified && other.time.second.specified {
+            return self.cmp(other);
+        }
+
+        self.truncate_to_minute().cmp(&other.truncate_to_minute())
+    }
+
+    /// Whether the datetime falls on `date`, regardless of its time.
+    pub fn is_on_date(&self, date: &YearMonthDay) -> bool {
+        self.date == *date
+    }
+
+    /// Compares only the date of the datetime with `date`, e.g. to find the datetimes on or after
+    /// a given day.
+    pub fn date_cmp(&self, date: &YearMonthDay) -> Ordering {
+        self.date.cmp(date)
+    }
+}
+
+impl YearMonthDay {
+    pub fn year(&self) -> i32 {
+        self.year.0
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month.0
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day.0
+    }
+
+    /// Combines the components into a date, failing if `day` doesn't exist in the given month.
+    pub fn from_components(year: Year, month: Month, day: Day) -> Result<Self, DateTimeParseError> {
+        if !is_valid_day(year, month, day) {
+            return Err(DateTimeParseError {
+                kind: DateTimeParseErrorKind::OutOfRange {
+                    min: 1,
+                    max: days_in_month(year, month) as i32,
+                },
+                found: day.0.to_string(),
+                component: Component::Day,
+                position: None,
+            });
+        }
+
+        Ok(YearMonthDay { year, month, day })
+    }
+}
+
+// Helper function to check if the given day is valid for the given year and month.
+fn is_valid_day(year: Year, month: Month, day: Day) -> bool {
+    day.0 <= days_in_month(year, month)
+}
+
+// The number of days in each month of a common year.
+const MONTH_LENGTHS: [u8; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+// Determines the number of days in a month. Everything that depends on the length of a month
+// goes through here, so that leap years are handled in one place.
+fn days_in_month(year: Year, month: Month) -> u8 {
+    let length = MONTH_LENGTHS[usize::from(month.0 - 1)];
+    if month.0 == 2 && year.is_leap() {
+        length + 1
+    } else {
+        length
+    }
+}
+
+/// Returns `true` if `year` is a leap year in the Gregorian calendar, i.e. if it's divisible by 4
+/// but, unless it's also divisible by 400, not by 100.
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
+impl Year {
+    /// The earliest year HTML allows.
+    pub const MIN: i32 = 1;
+    /// The latest year accepted. Browsers can't represent datetimes past 13 September 275760 (the
+    /// limit of a JavaScript `Date`), so no `<input type="datetime-local">` will ever submit a
+    /// later year. The rest of that year is still accepted, as only years are checked against
+    /// this limit, not whole datetimes.
+    pub const MAX: i32 = 275_760;
+
+    /// Like `try_from`, but for years computed in a wider type.
+    pub fn try_from_i64(value: i64) -> Result<Year, DateTimeParseError> {
+        Year::try_from(i32::try_from(value).unwrap_or(i32::MAX)).map_err(|err| DateTimeParseError {
+            found: value.to_string(),
+            ..err
+        })
+    }
+
+    /// Returns `true` if February has 29 days in this year.
+    pub fn is_leap(&self) -> bool {
+        is_leap_year(self.0)
+    }
+
+    /// Whether `datetime` falls within this year, at any time of any of its days.
+    pub fn contains(&self, datetime: &Datetime) -> bool {
+        datetime.date.year == *self
+    }
+}
+
+impl YearMonthDay {
+    // Number of days since 1970-01-01 in the proleptic Gregorian calendar.
+    fn days_since_epoch(&self) -> i64 {
+        let month = i64::from(self.month.0);
+        let year = i64::from(self.year.0) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5
+            + i64::from(self.day.0)
+            - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+
+    // Inverse of `days_since_epoch`. Returns `None` if the year isn't representable.
+    fn from_days_since_epoch(days: i64) -> Option<Self> {
+        let days = days.checked_add(719468)?;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Some(YearMonthDay {
+            year: Year::new(i32::try_from(year).ok()?)?,
+            month: Month(month as u8),
+            day: Day(day as u8),
+        })
+    }
+
+    // Adds calendar months, clamping the day to the length of the resulting month.
+    fn checked_add_months(&self, months: i64) -> Option<Self> {
+        let index = i64::from(self.year.0)
+            .checked_mul(12)?
+            .checked_add(i64::from(self.month.0) - 1)?
+            .checked_add(months)?;
+        let year = Year::try_from(i32::try_from(index.div_euclid(12)).ok()?).ok()?;
+        let month = Month(index.rem_euclid(12) as u8 + 1);
+        let day = Day(self.day.0.min(days_in_month(year, month)));
+
+        Some(YearMonthDay { year, month, day })
+    }
+}
+
+impl Datetime {
+    // Adds whole minutes, carrying into the date. The second is left untouched.
+    fn checked_add_minutes(&self, minutes: i64) -> Option<Self> {
+        let total = self
+            .date
+            .days_since_epoch()
+            .checked_mul(MINUTES_PER_DAY)?
+            .checked_add(i64::from(self.time.hour.0) * 60 + i64::from(self.time.minute.0))?
+            .checked_add(minutes)?;
+        let minute_of_day = total.rem_euclid(MINUTES_PER_DAY);
+
+        Some(Datetime {
+            date: YearMonthDay::from_days_since_epoch(total.div_euclid(MINUTES_PER_DAY))?,
+            time: HourMinuteSecond {
+                hour: Hour((minute_of_day / 60) as u8),
+                minute: Minute((minute_of_day % 60) as u8),
+                second: self.time.second,
+            },
+            separator: self.separator,
+            expanded_year_digits: self.expanded_year_digits,
+        })
+    }
+
+    fn nanos_since_epoch(&self) -> i128 {
+        let seconds = i128::from(self.date.days_since_epoch()) * i128::from(SECONDS_PER_DAY)
+            + i128::from(self.time.hour.0) * 3600
+            + i128::from(self.time.minute.0) * 60;
+
+        seconds * NANOS_PER_SECOND + i128::from(self.time.second.millis()) * NANOS_PER_MILLI
+    }
+
+    // Inverse of `nanos_since_epoch`, truncated to whole milliseconds. Returns `None` if the year
+    // isn't representable.
+    fn from_nanos_since_epoch(nanos: i128) -> Option<Self> {
+        let seconds = nanos.div_euclid(NANOS_PER_SECOND);
+        let subsec_nanos = nanos.rem_euclid(NANOS_PER_SECOND);
+        let days = i64::try_from(seconds.div_euclid(i128::from(SECONDS_PER_DAY))).ok()?;
+        let second_of_day = seconds.rem_euclid(i128::from(SECONDS_PER_DAY));
+
+        Some(Datetime {
+            date: YearMonthDay::from_days_since_epoch(days)?,
+            time: HourMinuteSecond {
+                hour: Hour((second_of_day / 3600) as u8),
+                minute: Minute((second_of_day / 60 % 60) as u8),
+                second: Second::new(
+                    ((second_of_day % 60) * 1000 + subsec_nanos / NANOS_PER_MILLI) as u16,
+                ),
+            },
+            separator: Separator::T,
+            expanded_year_digits: None,
+        })
+    }
+
+    fn checked_add_months(&self, months: i64) -> Option<Self> {
+        Some(Datetime {
+            date: self.date.checked_add_months(months)?,
+            time: self.time.clone(),
+            separator: self.separator,
+            expanded_year_digits: self.expanded_year_digits,
+        })
+    }
+
+    /// Returns the datetimes following `self` according to `rule`.
+    ///
+    /// The first item is one interval after `self`. Monthly steps are counted from `self`, so a
+    /// day that doesn't exist in some month is clamped to its last day without drifting the later
+    /// occurrences (`01-31`, `02-28`, `03-31`, ...). The iterator ends once the next occurrence
+    /// is no longer representable, and is empty when `rule.interval` is zero.
+    pub fn recur(&self, rule: RecurrenceRule) -> impl Iterator<Item = Datetime> {
+        let start = self.clone();
+
+        (1..)
+            .take_while(move |_| rule.interval != 0)
+            .map(move |step: i64| {
+                let amount = step.checked_mul(i64::from(rule.interval))?;
+                match rule.every {
+                    Unit::Minute => start.checked_add_minutes(amount),
+                    Unit::Hour => start.checked_add_minutes(amount.checked_mul(60)?),
+                    Unit::Day => start.checked_add_minutes(amount.checked_mul(MINUTES_PER_DAY)?),
+                    Unit::Month => start.checked_add_months(amount),
+                }
+            })
+            .take_while(Option::is_some)
+            .flatten()
+    }
+}
+
+impl Datetime {
+    /// Restricts `self` to the interval `[min, max]`, returning `min` if `self` is earlier and
+    /// `max` if it's later. Unlike [`Ord::clamp`], this borrows the bounds. `self` is taken by
+    /// value, as otherwise `dt.clamp(..)` would resolve to `Ord::clamp`.
+    ///
+    /// `min` must not be later than `max`, which is only checked in debug builds.
+    pub fn clamp(self, min: &Datetime, max: &Datetime) -> Datetime {
+        debug_assert!(min <= max, "min must not be later than max");
+
+        if self < *min {
+            min.clone()
+        } else if self > *max {
+            max.clone()
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if the time is exactly `00:00:00.000`.
+    pub fn is_midnight(&self) -> bool {
+        self.time.is_exactly(0, 0)
+    }
+
+    /// Returns `true` if the time is exactly `12:00:00.000`.
+    pub fn is_noon(&self) -> bool {
+        self.time.is_exactly(12, 0)
+    }
+}
+
+/// Midnight, `00:00:00`.
+impl Default for HourMinuteSecond {
+    fn default() -> Self {
+        HourMinuteSecond::MIDNIGHT
+    }
+}
+
+impl HourMinuteSecond {
+    const MIDNIGHT: HourMinuteSecond = HourMinuteSecond {
+        hour: Hour(0),
+        minute: Minute(0),
+        second: Second::new(0),
+    };
+
+    pub fn hour(&self) -> u8 {
+        self.hour.0
+    }
+
+    pub fn minute(&self) -> u8 {
+        self.minute.0
+    }
+
+    /// Returns the second including its fractional part.
+    pub fn second(&self) -> f32 {
+        self.second.as_f32()
+    }
+
+    /// Whether the time was written with seconds. Times that weren't parsed always have them.
+    pub fn seconds_specified(&self) -> bool {
+        self.second.specified
+    }
+
+    /// Combines the components into a time. Every combination of valid components is a valid
+    /// time, so this can't fail.
+    pub fn from_components(hour: Hour, minute: Minute, second: Second) -> Self {
+        HourMinuteSecond {
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Adds `minutes`, which may be negative, wrapping around midnight. Also returns the number of
+    /// days the result is after (or, if negative, before) the day of `self`.
+    ///
+    /// ```
+    /// use html_datetime_local::HourMinuteSecond;
+    ///
+    /// let time: HourMinuteSecond = "23:30:00".parse().unwrap();
+    /// let (later, days) = time.add_minutes(90);
+    /// assert_eq!((later.to_string().as_str(), days), ("01:00:00", 1));
+    /// ```
+    pub fn add_minutes(&self, minutes: i64) -> (HourMinuteSecond, i64) {
+        let minute_of_day = i64::from(self.hour.0) * 60
+            + i64::from(self.minute.0)
+            + minutes.rem_euclid(MINUTES_PER_DAY);
+        let days = minutes.div_euclid(MINUTES_PER_DAY) + minute_of_day / MINUTES_PER_DAY;
+        let minute_of_day = minute_of_day % MINUTES_PER_DAY;
+
+        let time = HourMinuteSecond {
+            hour: Hour((minute_of_day / 60) as u8),
+            minute: Minute((minute_of_day % 60) as u8),
+            second: self.second,
+        };
+        (time, days)
+    }
+
+    // Whether the time is the given hour and minute without any seconds, fractional or not.
+    fn is_exactly(&self, hour: u8, minute: u8) -> bool {
+        self.hour.0 == hour && self.minute.0 == minute && self.second.millis == 0
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    fn abbreviation(self) -> &'static str {
+        ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"][self as usize]
+    }
+
+    // Number of days from `first` until `self`, in the range `0..7`.
+    fn days_since(self, first: Weekday) -> u8 {
+        (self as u8 + 7 - first as u8) % 7
+    }
+}
+
+impl YearMonthDay {
+    /// Returns the day of the week in the proleptic Gregorian calendar.
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        Weekday::ALL[(self.days_since_epoch() + 3).rem_euclid(7) as usize]
+    }
+
+    /// Adds `days`, crossing into other months and years as needed. Returns `None` if the result
+    /// is outside of the representable years.
+    pub fn checked_add_days(&self, days: i64) -> Option<YearMonthDay> {
+        Self::from_days_since_epoch(self.days_since_epoch().checked_add(days)?)
+    }
+
+    /// Subtracts `days`, crossing into other months and years as needed. Returns `None` if the
+    /// result is outside of the representable years.
+    pub fn checked_sub_days(&self, days: i64) -> Option<YearMonthDay> {
+        Self::from_days_since_epoch(self.days_since_epoch().checked_sub(days)?)
+    }
+
+    /// Returns each day from `self` up to, but excluding, `end`.
+    ///
+    /// ```
+    /// use html_datetime_local::YearMonthDay;
+    ///
+    /// let start: YearMonthDay = "2024-02-28".parse().unwrap();
+    /// let days: Vec<String> = start
+    ///     .iter_until("2024-03-02".parse().unwrap())
+    ///     .map(|day| day.to_string())
+    ///     .collect();
+    /// assert_eq!(days, ["2024-02-28", "2024-02-29", "2024-03-01"]);
+    /// ```
+    pub fn iter_until(&self, end: YearMonthDay) -> impl Iterator<Item = YearMonthDay> {
+        (self.days_since_epoch()..end.days_since_epoch()).map_while(Self::from_days_since_epoch)
+    }
+
+    /// Returns the number of full years from `self` until `other`, e.g. someone's age on `other`
+    /// if they were born on `self`. A year is complete once the month and the day of `self` have
+    /// been reached. For February 29, that's February 28 in common years. The result is negative
+    /// if `other` is earlier.
+    ///
+    /// ```
+    /// use html_datetime_local::YearMonthDay;
+    ///
+    /// let birthday: YearMonthDay = "2000-02-29".parse().unwrap();
+    /// assert_eq!(birthday.full_years_until(&"2023-02-27".parse().unwrap()), 22);
+    /// assert_eq!(birthday.full_years_until(&"2023-02-28".parse().unwrap()), 23);
+    /// ```
+    pub fn full_years_until(&self, other: &YearMonthDay) -> i32 {
+        if other < self {
+            return -other.full_years_until(self);
+        }
+
+        let years = other.year.0 - self.year.0;
+        let anniversary = self
+            .checked_add_months(i64::from(years) * 12)
+            .expect("The year of `other` is representable");
+        if anniversary > *other {
+            years - 1
+        } else {
+            years
+        }
+    }
+
+    /// Returns the 1-based day of the year, in the range `1..=366`.
+    pub fn ordinal(&self) -> u16 {
+        let preceding_months: u16 = (1..self.month.0)
+            .map(|month| u16::from(days_in_month(self.year, Month(month))))
+            .sum();
+
+        preceding_months + u16::from(self.day.0)
+    }
+
+    /// Returns the quarter of the year, in the range `1..=4`: January to March is the first.
+    pub fn quarter(&self) -> u8 {
+        (self.month.0 - 1) / 3 + 1
+    }
+
+    /// Returns the ISO 8601 week-numbering year and week, in the range `1..=53`, that this date
+    /// belongs to. Weeks start on Monday and week 1 is the one with the year's first Thursday, so
+    /// the first and last days of a year can belong to a week of a neighboring year.
+    ///
+    /// ```
+    /// use html_datetime_local::YearMonthDay;
+    ///
+    /// let date: YearMonthDay = "2021-01-01".parse().unwrap();
+    /// assert_eq!(date.iso_week(), (2020, 53));
+    /// ```
+    pub fn iso_week(&self) -> (i32, u8) {
+        let weekday = i32::from(self.weekday().days_since(Weekday::Monday)) + 1;
+        let week = (i32::from(self.ordinal()) - weekday + 10) / 7;
+
+        if week < 1 {
+            let previous = Year(self.year.0 - 1);
+            (previous.0, weeks_in_year(previous))
+        } else if week > i32::from(weeks_in_year(self.year)) {
+            (self.year.0 + 1, 1)
+        } else {
+            (self.year.0, week as u8)
+        }
+    }
+
+    /// Returns the 1-based row in which this date appears in a calendar grid of its month whose
+    /// weeks start on `first_day_of_week`. The result is in the range `1..=6`.
+    pub fn week_of_month(&self, first_day_of_week: Weekday) -> u8 {
+        let first_of_month = YearMonthDay {
+            day: Day(1),
+            ..self.clone()
+        };
+        let leading_days = first_of_month.weekday().days_since(first_day_of_week);
+
+        (self.day.0 - 1 + leading_days) / 7 + 1
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time"))]
+impl Second {
+    // Splits the value into whole seconds and nanoseconds.
+    fn whole_and_nanos(self) -> (u8, u32) {
+        (self.whole(), self.millis() % 1000 * 1_000_000)
+    }
+
+    // Inverse of `whole_and_nanos`, truncated to whole milliseconds.
+    fn from_whole_and_nanos(whole: u8, nanos: u32) -> Result<Self, DateTimeParseError> {
+        Self::from_millis(u32::from(whole) * 1000 + nanos / 1_000_000)
+    }
+}
+
+// Rounds half away from zero like `f64::round`, which isn't available without `std`.
+fn round_non_negative(value: f64) -> u64 {
+    (value + 0.5) as u64
+}
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+const SECONDS_PER_DAY: i64 = MINUTES_PER_DAY * 60;
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_MILLI: i128 = 1_000_000;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Unit {
+    Minute,
+    Hour,
+    Day,
+    Month,
+}
+
+/// A repetition of `interval` units, e.g. every 2 hours.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct RecurrenceRule {
+    pub every: Unit,
+    pub interval: u32,
+}
+
+impl FromStr for HourMinuteSecond {
+    type Err = DateTimeParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(DatetimeParser::new().parse_time(value)?)
+    }
+}
+
+impl TryFrom<&str> for HourMinuteSecond {
+    type Error = DateTimeParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl Datetime {
+    /// Parses `s` according to the `strptime`-style format description `fmt`.
+    ///
+    /// The supported specifiers are `%Y` (year), `%m` (month), `%d` (day), `%H` (hour),
+    /// `%M` (minute), `%S` (second, optionally with a fraction) and `%%` (a literal `%`). Any
+    /// other character in `fmt` has to appear verbatim in `s`. `%Y` consumes all consecutive
+    /// digits, the others consume one or two. `%S` may be left out, in which case the second
+    /// is zero. The parsed components are validated just like in [`Datetime::from_str`].
+    ///
+    /// Errors about the input carry their byte offset in `s`. An
+    /// [`UnsupportedSpecifier`](DateTimeParseErrorKind::UnsupportedSpecifier) is a mistake in
+    /// `fmt` rather than in `s`, so its position is `None`.
+    pub fn parse_from_format(s: &str, fmt: &str) -> Result<Datetime, DateTimeParseError> {
+        let mut input = s;
+        let mut year = None;
+        let mut month = None;
+        let mut day = None;
+        let mut hour = None;
+        let mut minute = None;
+        let mut second = None;
+
+        let mut specifiers = fmt.chars();
+        while let Some(c) = specifiers.next() {
+            let offset = s.len() - input.len();
+            if c != '%' {
+                input = strip_literal(input, c, offset)?;
+                continue;
+            }
+
+            // Component errors are positioned relative to the start of the component.
+            let at_offset = |err: DateTimeParseError| err.offset_by(offset);
+            match specifiers.next() {
+                Some('%') => input = strip_literal(input, '%', offset)?,
+                Some('Y') => {
+                    year = Some(
+                        Year::from_str(next_digits(&mut input, usize::MAX)).map_err(at_offset)?,
+                    )
+                }
+                Some('m') => {
+                    month = Some(Month::from_str(next_digits(&mut input, 2)).map_err(at_offset)?)
+                }
+                Some('d') => {
+                    day = Some(Day::from_str(next_digits(&mut input, 2)).map_err(at_offset)?)
+                }
+                Some('H') => {
+                    hour = Some(Hour::from_str(next_digits(&mut input, 2)).map_err(at_offset)?)
+                }
+                Some('M') => {
+                    minute = Some(Minute::from_str(next_digits(&mut input, 2)).map_err(at_offset)?)
+                }
+                Some('S') => {
+                    let start = input;
+                    next_digits(&mut input, 2);
+                    if let Some(fraction) = input.strip_prefix('.') {
+                        input = fraction;
+                        next_digits(&mut input, usize::MAX);
+                    }
+                    second = Some(
+                        Second::from_str(&start[..start.len() - input.len()]).map_err(at_offset)?,
+                    );
+                }
+                other => {
+                    return Err(DateTimeParseError {
+                        component: Component::Format,
+                        found: other.map_or("%".to_string(), |c| format!("%{c}")),
+                        kind: DateTimeParseErrorKind::UnsupportedSpecifier,
+                        position: None,
+                    })
+                }
+            }
+        }
+
+        if !input.is_empty() {
+            return Err(DateTimeParseError {
+                component: Component::Format,
+                found: input.to_string(),
+                kind: DateTimeParseErrorKind::UnexpectedCharacters,
+                position: Some(s.len() - input.len()),
+            });
+        }
+
+        let missing = |component| DateTimeParseError {
+            component,
+            found: "".to_string(),
+            kind: DateTimeParseErrorKind::Missing,
+            position: None,
+        };
+
+        Ok(Datetime {
+            date: YearMonthDay::from_components(
+                year.ok_or_else(|| missing(Component::Year))?,
+                month.ok_or_else(|| missing(Component::Month))?,
+                day.ok_or_else(|| missing(Component::Day))?,
+            )?,
+            time: HourMinuteSecond {
+                hour: hour.ok_or_else(|| missing(Component::Hour))?,
+                minute: minute.ok_or_else(|| missing(Component::Minute))?,
+                second: second.unwrap_or(Second::UNSPECIFIED),
+            },
+            separator: Separator::T,
+            expanded_year_digits: None,
+        })
+    }
+}
+
+// Splits off the leading run of at most `max` ASCII digits from `input`.
+fn next_digits<'a>(input: &mut &'a str, max: usize) -> &'a str {
+    let (digits, rest) = scanner::split_digits(input, max);
+    *input = rest;
+    digits
+}
+
+// Strips the literal `expected` from the start of `input`, which starts at byte `offset` of the
+// whole input.
+fn strip_literal(input: &str, expected: char, offset: usize) -> Result<&str, DateTimeParseError> {
+    input
+        .strip_prefix(expected)
+        .ok_or_else(|| DateTimeParseError {
+            component: Component::Format,
+            found: input.to_string(),
+            kind: DateTimeParseErrorKind::UnexpectedCharacters,
+            position: Some(offset),
+        })
+}
+
+impl Datetime {
+    /// Encodes `self` as the 19 ASCII bytes `YYYY-MM-DDTHH:MM:SS`.
+    ///
+    /// The fixed width keeps the encoding byte-wise comparable, at the cost of precision: the
+    /// fractional part of the second is dropped, so [`Datetime::from_fixed19`] only restores
+    /// whole seconds.
+    ///
+    /// Returns `None` for years after 9999, which don't fit in four digits.
+    ///
+    /// ```
+    /// use html_datetime_local::Datetime;
+    ///
+    /// let dt: Datetime = "9999-12-31T23:59:59.999".parse().unwrap();
+    /// assert_eq!(&dt.to_fixed19().unwrap(), b"9999-12-31T23:59:59");
+    ///
+    /// let dt: Datetime = "10000-01-01T00:00".parse().unwrap();
+    /// assert_eq!(dt.to_fixed19(), None);
+    /// ```
+    pub fn to_fixed19(&self) -> Option<[u8; 19]> {
+        if self.date.year.0 > 9999 {
+            return None;
+        }
+
+        let encoded = format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.date.year.0,
+            self.date.month.0,
+            self.date.day.0,
+            self.time.hour.0,
+            self.time.minute.0,
+            self.time.second.whole(),
+        );
+
+        let mut bytes = [0; 19];
+        bytes.copy_from_slice(encoded.as_bytes());
+        Some(bytes)
+    }
+
+    /// Decodes the layout produced by [`Datetime::to_fixed19`]. The second has no fractional part.
+    pub fn from_fixed19(bytes: &[u8; 19]) -> Result<Datetime, DateTimeParseError> {
+        for (index, separator, component) in [
+            (4, b'-', Component::Date),
+            (7, b'-', Component::Date),
+            (10, b'T', Component::Time),
+            (13, b':', Component::Time),
+            (16, b':', Component::Time),
+        ] {
+            if bytes[index] != separator {
+                return Err(DateTimeParseError {
+                    component,
+                    found: String::from_utf8_lossy(&bytes[index..=index]).into_owned(),
+                    kind: DateTimeParseErrorKind::UnexpectedCharacters,
+                    position: Some(index),
+                });
+            }
+        }
+
+        // Parses the field at `range`, with error positions relative to the start of `bytes`.
+        fn field<T: FromStr<Err = DateTimeParseError>>(
+            bytes: &[u8; 19],
+            range: Range<usize>,
+            component: Component,
+        ) -> Result<T, DateTimeParseError> {
+            let text = core::str::from_utf8(&bytes[range.clone()]).map_err(|source| {
+                DateTimeParseError {
+                    component,
+                    found: String::from_utf8_lossy(&bytes[range.clone()]).into_owned(),
+                    kind: DateTimeParseErrorKind::InvalidNumber(source.into()),
+                    position: Some(range.start),
+                }
+            })?;
+            T::from_str(text).map_err(|err| err.offset_by(range.start))
+        }
+
+        let date = YearMonthDay::from_components(
+            field(bytes, 0..4, Component::Year)?,
+            field(bytes, 5..7, Component::Month)?,
+            field(bytes, 8..10, Component::Day)?,
+        )
+        .map_err(|err| DateTimeParseError {
+            position: Some(8),
+            ..err
+        })?;
+
+        Ok(Datetime {
+            date,
+            time: HourMinuteSecond {
+                hour: field(bytes, 11..13, Component::Hour)?,
+                minute: field(bytes, 14..16, Component::Minute)?,
+                second: field(bytes, 17..19, Component::Second)?,
+            },
+            separator: Separator::T,
+            expanded_year_digits: None,
+        })
+    }
+}
+
+/// The datetimes from `start` (inclusive) up to `end` (exclusive).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DatetimeRange {
+    pub start: Datetime,
+    pub end: Datetime,
+}
+
+impl DatetimeRange {
+    /// Returns `start`, `start + step`, `start + 2 * step`, ... for as long as the values are
+    /// earlier than `end`. Steps carry across day, month and year boundaries.
+    ///
+    /// A zero `step` would never reach `end`, so it yields an empty iterator instead.
+    pub fn iter_step(&self, step: Duration) -> impl Iterator<Item = Datetime> {
+        let end = self.end.clone();
+        // Accumulate in nanoseconds, so that steps finer than a millisecond still add up.
+        let step = step.as_nanos() as i128;
+        let first = (step != 0).then(|| self.start.nanos_since_epoch());
+
+        iter::successors(first, move |nanos| nanos.checked_add(step))
+            .map_while(Datetime::from_nanos_since_epoch)
+            .take_while(move |current| *current < end)
+    }
+}
+
+/// Controls how [`Datetime::format`] writes a value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct FormatOptions {
+    pub trailing_fraction_zeros: TrailingFractionZeros,
+    /// Write the separator the value was parsed with instead of the normalized `T`.
+    pub preserve_separator: bool,
+}
+
+/// What happens to zeros at the end of a fractional second. Unless the digits are written as
+/// parsed, a whole second is written without a fraction.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum TrailingFractionZeros {
+    /// Write all three digits of millisecond precision, e.g. `56.100`.
+    Keep,
+    /// Drop the trailing zeros like HTML's normalization does, e.g. `56.1`.
+    #[default]
+    Strip,
+    /// Write as many digits as the value was parsed with, e.g. `56.10`.
+    AsParsed,
+}
+
+// Used by `Display`, so that parsing and then displaying a value reproduces the input.
+const DISPLAY_OPTIONS: FormatOptions = FormatOptions {
+    trailing_fraction_zeros: TrailingFractionZeros::AsParsed,
+    preserve_separator: false,
+};
+
+impl Datetime {
+    /// Formats `self` as `YYYY-MM-DDTHH:MM:SS`, followed by the fractional second if there is
+    /// one. The fraction is rounded to milliseconds.
+    pub fn format(&self, options: &FormatOptions) -> String {
+        let mut out = String::new();
+        self.write(&mut out, options)
+            .expect("Writing to a String doesn't fail");
+        out
+    }
+
+    /// Formats `self` like `Display`, but with exactly `digits` fractional digits of the second,
+    /// rounded half to even. Rounding may carry into the minute, e.g. `12:34:59.96` with one
+    /// digit is `12:35:00.0`.
+    ///
+    /// ```
+    /// use html_datetime_local::Datetime;
+    ///
+    /// let dt: Datetime = "2023-12-18T12:34:56.5".parse().unwrap();
+    /// assert_eq!(dt.format_with_precision(3), "2023-12-18T12:34:56.500");
+    /// assert_eq!(dt.format_with_precision(0), "2023-12-18T12:34:56");
+    /// ```
+    pub fn format_with_precision(&self, digits: u8) -> String {
+        let unit = 10u32.pow(3 - u32::from(digits.min(3)));
+        let (quotient, remainder) = (
+            self.time.second.millis() / unit,
+            self.time.second.millis() % unit,
+        );
+        let round_up = remainder * 2 > unit || remainder * 2 == unit && quotient % 2 == 1;
+        let mut millis = (quotient + u32::from(round_up)) * unit;
+
+        let mut datetime = self.clone();
+        if millis >= MILLIS_PER_MINUTE {
+            match self.checked_add_minutes(1) {
+                Some(next) => {
+                    datetime = next;
+                    millis = 0;
+                }
+                // There is no next minute after `Datetime::MAX`, so round down instead.
+                None => millis -= unit,
+            }
+        }
+
+        // Display writes as many fractional digits as the second claims to have, up to three.
+        datetime.time.second = Second {
+            fractional_digits: digits.min(3),
+            ..Second::new(millis as u16)
+        };
+        let mut out = datetime.to_string();
+        out.extend(iter::repeat_n('0', usize::from(digits.saturating_sub(3))));
+        out
+    }
+
+    fn write(&self, out: &mut impl fmt::Write, options: &FormatOptions) -> fmt::Result {
+        let separator = if options.preserve_separator {
+            self.separator
+        } else {
+            Separator::T
+        };
+        if let Some(digits) = self.expanded_year_digits {
+            let date = &self.date;
+            write!(
+                out,
+                "+{:0width$}-{:02}-{:02}",
+                date.year.0,
+                date.month.0,
+                date.day.0,
+                width = usize::from(digits)
+            )?;
+        } else {
+            write!(out, "{}", self.date)?;
+        }
+        out.write_char(separator.as_char())?;
+        self.time.write(out, options)
+    }
+}
+
+impl fmt::Display for YearMonthDay {
+    /// Writes the value as `YYYY-MM-DD`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            self.year.0, self.month.0, self.day.0
+        )
+    }
+}
+
+impl HourMinuteSecond {
+    fn write(&self, out: &mut impl fmt::Write, options: &FormatOptions) -> fmt::Result {
+        write!(out, "{:02}:{:02}:", self.hour.0, self.minute.0)?;
+        self.second.write(out, options)
+    }
+}
+
+impl fmt::Display for HourMinuteSecond {
+    /// Writes the value as `HH:MM:SS`, followed by the fractional second if there is one.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, &DISPLAY_OPTIONS)
+    }
+}
+
+impl fmt::Display for Datetime {
+    /// Writes the value as `YYYY-MM-DDTHH:MM:SS`, which [`Datetime::from_str`] parses back. The
+    /// fractional second is written with as many digits as it was parsed with, or otherwise only
+    /// if there is one, without trailing zeros.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, &DISPLAY_OPTIONS)
+    }
+}
+
+/// Same as [`Datetime::to_string`](ToString::to_string).
+impl From<Datetime> for String {
+    fn from(datetime: Datetime) -> Self {
+        datetime.to_string()
+    }
+}
+
+/// Same as [`Datetime::to_string`](ToString::to_string).
+impl From<&Datetime> for String {
+    fn from(datetime: &Datetime) -> Self {
+        datetime.to_string()
+    }
+}
+
+impl Second {
+    fn write(&self, out: &mut impl fmt::Write, options: &FormatOptions) -> fmt::Result {
+        write!(out, "{:02}", self.whole())?;
+
+        let fraction = self.millis % 1000;
+        let digits = match options.trailing_fraction_zeros {
+            TrailingFractionZeros::Keep if fraction != 0 => 3,
+            TrailingFractionZeros::Keep => 0,
+            TrailingFractionZeros::Strip => Second::new(self.millis).fractional_digits,
+            TrailingFractionZeros::AsParsed => self.fractional_digits,
+        };
+        if digits == 0 {
+            return Ok(());
+        }
+
+        let fraction = fraction / 10u16.pow(3 - u32::from(digits));
+        write!(out, ".{fraction:0width$}", width = usize::from(digits))
+    }
+}
+
+impl Month {
+    const NAMES: [&'static str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    /// Returns the English name of the month, e.g. `January`.
+    pub fn name(&self) -> &'static str {
+        Self::NAMES[usize::from(self.0 - 1)]
+    }
+
+    /// Parses the full English name of a month, ignoring case, e.g. `january` or `JANUARY`.
+    pub fn from_name(name: &str) -> Option<Month> {
+        let index = Self::NAMES
+            .iter()
+            .position(|candidate| candidate.eq_ignore_ascii_case(name))?;
+        Some(Month(index as u8 + 1))
+    }
+
+    fn abbreviation(self) -> &'static str {
+        &self.name()[..3]
+    }
+}
+
+impl Datetime {
+    /// Formats `self` like the date-time of RFC 2822, e.g. `Mon, 18 Dec 2023 12:34:56`.
+    ///
+    /// This isn't a complete RFC 2822 date-time since a local datetime has no zone offset to
+    /// append. The fractional second is truncated.
+    pub fn to_rfc2822_local(&self) -> String {
+        format!(
+            "{}, {} {} {:04} {:02}:{:02}:{:02}",
+            self.date.weekday().abbreviation(),
+            self.date.day.0,
+            self.date.month.abbreviation(),
+            self.date.year.0,
+            self.time.hour.0,
+            self.time.minute.0,
+            self.time.second.whole(),
+        )
+    }
+}
+
+impl Datetime {
+    // The "valid normalized local date and time string" of the HTML standard: a `T` separator and
+    // the shortest time representation, which leaves out a zero second.
+    fn normalized(&self) -> String {
+        let mut out = self.format(&FormatOptions::default());
+        if self.time.second.millis == 0 {
+            out.truncate(out.len() - ":00".len());
+        }
+        out
+    }
+}
+
+/// Checks whether `s` is already in the normalized form of the HTML standard, i.e. whether
+/// normalizing the parsed value would reproduce `s` byte for byte. Fields with a single digit,
+/// such as in `2023-1-2T3:4`, are parsed but aren't normalized.
+pub fn is_already_normalized(s: &str) -> Result<bool, DateTimeParseError> {
+    let datetime = DatetimeParser::new()
+        .allow_single_digit_fields(true)
+        .parse(s)?;
+    Ok(datetime.normalized() == s)
+}
+
+/// A yearless date such as `--12-18`, used for recurring annual dates like birthdays.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct MonthDay {
+    month: Month,
+    day: Day,
+}
+
+impl MonthDay {
+    pub fn from_components(month: Month, day: Day) -> Result<Self, DateTimeParseError> {
+        // Without a year, February 29 has to be allowed.
+        let max = days_in_month(Year(2000), month);
+        if day.0 > max {
+            return Err(DateTimeParseError {
+                component: Component::Day,
+                found: day.0.to_string(),
+                kind: DateTimeParseErrorKind::OutOfRange {
+                    min: 1,
+                    max: max as i32,
+                },
+                position: None,
+            });
+        }
+
+        Ok(MonthDay { month, day })
+    }
+
+    /// Binds the date to `year`. February 29 fails for years that aren't leap years.
+    pub fn in_year(&self, year: i32) -> Result<YearMonthDay, DateTimeParseError> {
+        YearMonthDay::from_components(Year::try_from(year)?, self.month, self.day)
+    }
+}
+
+impl FromStr for MonthDay {
+    type Err = DateTimeParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        // HTML makes the leading hyphens optional.
+        let rest = value.strip_prefix("--").unwrap_or(value);
+        let (month, day) = rest.split_once('-').ok_or_else(|| DateTimeParseError {
+            component: Component::Day,
+            found: "".to_string(),
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(value.len()),
+        })?;
+        let month_offset = value.len() - rest.len();
+        let day_offset = month_offset + month.len() + 1;
+
+        let month = two_digits(month, Component::Month)
+            .and_then(Month::from_str_borrowed)
+            .map_err(|err| err.offset_by(month_offset))?;
+        let day = two_digits(day, Component::Day)
+            .and_then(Day::from_str_borrowed)
+            .map_err(|err| err.offset_by(day_offset))?;
+
+        Self::from_components(month, day).map_err(|err| DateTimeParseError {
+            position: Some(day_offset),
+            ..err
+        })
+    }
+}
+
+impl fmt::Display for MonthDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "--{:02}-{:02}", self.month.0, self.day.0)
+    }
+}
+
+/// A month of a year, such as the value of `<input type="month">`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct YearMonth {
+    year: Year,
+    month: Month,
+}
+
+impl YearMonth {
+    pub fn year(&self) -> i32 {
+        self.year.0
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month.0
+    }
+
+    /// Returns the number of days in the month, taking leap years into account.
+    pub fn length_in_days(&self) -> u8 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Whether `date` is one of the days of this month.
+    ///
+    /// ```
+    /// use html_datetime_local::YearMonth;
+    ///
+    /// let month: YearMonth = "2023-12".parse().unwrap();
+    /// assert!(month.contains(&"2023-12-31".parse().unwrap()));
+    /// assert!(!month.contains(&"2024-12-01".parse().unwrap()));
+    /// ```
+    pub fn contains(&self, date: &YearMonthDay) -> bool {
+        date.year == self.year && date.month == self.month
+    }
+}
+
+impl FromStr for YearMonth {
+    type Err = DateTimeParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut parts = value.splitn(3, '-');
+
+        let year = parts.next().unwrap_or_default();
+        let month = parts.next().ok_or_else(|| DateTimeParseError {
+            component: Component::Month,
+            found: "".to_string(),
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(value.len()),
+        })?;
+        let month_offset = year.len() + 1;
+        if let Some(rest) = parts.next() {
+            return Err(DateTimeParseError {
+                component: Component::Date,
+                found: format!("-{rest}"),
+                kind: DateTimeParseErrorKind::UnexpectedCharacters,
+                position: Some(month_offset + month.len()),
+            });
+        }
+
+        Ok(YearMonth {
+            year: Year::from_str(year_digits(year)?)?,
+            month: two_digits(month, Component::Month)
+                .and_then(Month::from_str_borrowed)
+                .map_err(|err| err.offset_by(month_offset))?,
+        })
+    }
+}
+
+impl fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year.0, self.month.0)
+    }
+}
+
+/// An ISO 8601 week of a year, such as the value of `<input type="week">`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub struct YearWeek {
+    year: Year,
+    week: u8,
+}
+
+impl YearWeek {
+    pub fn year(&self) -> i32 {
+        self.year.0
+    }
+
+    pub fn week(&self) -> u8 {
+        self.week
+    }
+}
+
+// Number of ISO weeks in `year`. A year has 53 of them if it starts on a Thursday, or if it's a
+// leap year starting on a Wednesday, and 52 otherwise.
+fn weeks_in_year(year: Year) -> u8 {
+    let new_year = YearMonthDay {
+        year,
+        month: Month(1),
+        day: Day(1),
+    };
+
+    match new_year.weekday() {
+        Weekday::Thursday => 53,
+        Weekday::Wednesday if year.is_leap() => 53,
+        _ => 52,
+    }
+}
+
+impl FromStr for YearWeek {
+    type Err = DateTimeParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (year, week) = value.split_once('-').ok_or_else(|| DateTimeParseError {
+            component: Component::Week,
+            found: "".to_string(),
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(value.len()),
+        })?;
+        let week_offset = year.len() + 1;
+        let week = week.strip_prefix('W').ok_or_else(|| DateTimeParseError {
+            component: Component::Week,
+            found: week.to_string(),
+            kind: DateTimeParseErrorKind::UnexpectedCharacters,
+            position: Some(week_offset),
+        })?;
+
+        let year = Year::from_str(year_digits(year)?)?;
+        let week_digits =
+            two_digits(week, Component::Week).map_err(|err| err.offset_by(week_offset + 1))?;
+        let max = weeks_in_year(year);
+        let week = week_digits
+            .parse::<u8>()
+            .ok()
+            .filter(|week| (1..=max).contains(week))
+            .ok_or_else(|| DateTimeParseError {
+                component: Component::Week,
+                found: week_digits.to_string(),
+                kind: DateTimeParseErrorKind::OutOfRange {
+                    min: 1,
+                    max: max as i32,
+                },
+                position: Some(week_offset + 1),
+            })?;
+
+        Ok(YearWeek { year, week })
+    }
+}
+
+impl fmt::Display for YearWeek {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-W{:02}", self.year.0, self.week)
+    }
+}
+
+/// The difference between a local time and UTC.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct TimezoneOffset {
+    minutes: i16,
+}
+
+impl TimezoneOffset {
+    pub const UTC: TimezoneOffset = TimezoneOffset { minutes: 0 };
+
+    const MAX_MINUTES: i16 = 23 * 60 + 59;
+
+    /// Creates an offset of `minutes` east of UTC, which must be at most 23 hours and 59 minutes
+    /// in either direction.
+    pub fn from_minutes(minutes: i16) -> Result<Self, DateTimeParseError> {
+        if !(-Self::MAX_MINUTES..=Self::MAX_MINUTES).contains(&minutes) {
+            return Err(DateTimeParseError {
+                component: Component::Offset,
+                found: minutes.to_string(),
+                kind: DateTimeParseErrorKind::OutOfRange {
+                    min: -Self::MAX_MINUTES as i32,
+                    max: Self::MAX_MINUTES as i32,
+                },
+                position: None,
+            });
+        }
+
+        Ok(TimezoneOffset { minutes })
+    }
+
+    /// Returns the offset in minutes east of UTC.
+    pub fn minutes(&self) -> i16 {
+        self.minutes
+    }
+}
+
+impl FromStr for TimezoneOffset {
+    type Err = DateTimeParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == "Z" {
+            return Ok(TimezoneOffset::UTC);
+        }
+
+        let invalid = |kind| DateTimeParseError {
+            component: Component::Offset,
+            found: value.to_string(),
+            kind,
+            position: Some(0),
+        };
+
+        let (sign, rest) = match value.split_at_checked(1) {
+            Some(("+", rest)) => (1, rest),
+            Some(("-", rest)) => (-1, rest),
+            _ => return Err(invalid(DateTimeParseErrorKind::UnexpectedCharacters)),
+        };
+        // HTML makes the colon optional, i.e. `+0530` is the same as `+05:30`.
+        let (hours, minutes, minutes_offset) = match rest.split_once(':') {
+            Some((hours, minutes)) => (hours, minutes, hours.len() + 2),
+            None => {
+                let (hours, minutes) = rest.split_at_checked(2).unwrap_or((rest, ""));
+                (hours, minutes, 3)
+            }
+        };
+
+        let hours: i16 = two_digits(hours, Component::Offset)
+            .map_err(|err| err.offset_by(1))?
+            .parse()
+            .expect("Two ASCII digits form a valid number");
+        if minutes.is_empty() {
+            return Err(invalid(DateTimeParseErrorKind::Missing));
+        }
+        let minutes: i16 = two_digits(minutes, Component::Offset)
+            .map_err(|err| err.offset_by(minutes_offset))?
+            .parse()
+            .expect("Two ASCII digits form a valid number");
+        if hours > 23 || minutes > 59 {
+            return Err(invalid(DateTimeParseErrorKind::OutOfRange {
+                min: -Self::MAX_MINUTES as i32,
+                max: Self::MAX_MINUTES as i32,
+            }));
+        }
+
+        Self::from_minutes(sign * (hours * 60 + minutes))
+    }
+}
+
+impl fmt::Display for TimezoneOffset {
+    /// Formats UTC as `Z` and any other offset as `+HH:MM` or `-HH:MM`, with the colon.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.minutes == 0 {
+            return f.write_str("Z");
+        }
+
+        let sign = if self.minutes < 0 { '-' } else { '+' };
+        let minutes = self.minutes.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+    }
+}
+
+/// A local date and time together with its offset from UTC, such as
+/// `2023-12-18T12:34:56+05:30`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GlobalDatetime {
+    pub datetime: Datetime,
+    pub offset: TimezoneOffset,
+}
+
+impl FromStr for GlobalDatetime {
+    type Err = DateTimeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // The offset is the first `Z`, `+` or `-` after the start of the time. Without a time,
+        // parsing the whole input as a datetime reports what's missing.
+        let time_start = s.find(['T', ' ']).unwrap_or(s.len());
+        let offset_start = s[time_start..]
+            .find(['Z', '+', '-'])
+            .map_or(s.len(), |index| time_start + index);
+        let (datetime, offset) = s.split_at(offset_start);
+
+        let datetime = Datetime::from_str(datetime)?;
+        if offset.is_empty() {
+            return Err(DateTimeParseError {
+                component: Component::Offset,
+                found: "".to_string(),
+                kind: DateTimeParseErrorKind::Missing,
+                position: Some(s.len()),
+            });
+        }
+
+        Ok(GlobalDatetime {
+            datetime,
+            offset: TimezoneOffset::from_str(offset).map_err(|err| err.offset_by(offset_start))?,
+        })
+    }
+}
+
+impl fmt::Display for GlobalDatetime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.datetime, self.offset)
+    }
+}
+
+impl GlobalDatetime {
+    /// Formats the value as an RFC 3339 date-time, e.g. `2023-12-18T12:34:56+05:30` or
+    /// `2023-12-18T12:34:56Z`.
+    ///
+    /// Unlike HTML, RFC 3339 requires the seconds, the `T` separator and a year without a sign,
+    /// so those are always written that way. It only covers four-digit years, though: a later
+    /// year is written in full, which not every RFC 3339 parser accepts.
+    ///
+    /// ```
+    /// use html_datetime_local::GlobalDatetime;
+    ///
+    /// let dt: GlobalDatetime = "2023-12-18 12:34-08:00".parse().unwrap();
+    /// assert_eq!(dt.to_rfc3339(), "2023-12-18T12:34:00-08:00");
+    /// ```
+    pub fn to_rfc3339(&self) -> String {
+        format!(
+            "{}T{}{}",
+            self.datetime.date, self.datetime.time, self.offset
+        )
+    }
+
+    /// Returns the same instant as a UTC wall-clock time, e.g. `2023-12-18T23:30+05:30` is
+    /// `2023-12-18T18:00` in UTC. Returns `None` if that falls outside of the representable
+    /// years, as `0001-01-01T00:00+01:00` does.
+    ///
+    /// ```
+    /// use html_datetime_local::GlobalDatetime;
+    ///
+    /// let dt: GlobalDatetime = "2023-12-31T22:00-05:00".parse().unwrap();
+    /// assert_eq!(dt.to_utc().unwrap().to_string(), "2024-01-01T03:00:00");
+    /// ```
+    pub fn to_utc(&self) -> Option<Datetime> {
+        self.datetime
+            .checked_add_minutes(-i64::from(self.offset.minutes))
+    }
+}
+
+/// A value in any of the date and time formats of HTML, e.g. whatever an `<input>` of unknown
+/// `type` submitted.
+///
+/// ```
+/// use html_datetime_local::DatetimeValue;
+///
+/// assert!(matches!(
+///     DatetimeValue::parse("2023-W51").unwrap(),
+///     DatetimeValue::Week(_)
+/// ));
+/// assert!(matches!(
+///     DatetimeValue::parse("2023-12-18T12:34").unwrap(),
+///     DatetimeValue::Local(_)
+/// ));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DatetimeValue {
+    /// `2023-12-18`, from `<input type="date">`.
+    Date(YearMonthDay),
+    /// `2023-12`, from `<input type="month">`.
+    Month(YearMonth),
+    /// `2023-W51`, from `<input type="week">`.
+    Week(YearWeek),
+    /// `--12-18` or `12-18`.
+    Yearless(MonthDay),
+    /// `12:34:56`, from `<input type="time">`.
+    Time(HourMinuteSecond),
+    /// `2023-12-18T12:34:56`, from `<input type="datetime-local">`.
+    Local(Datetime),
+    /// `2023-12-18T12:34:56+05:30`.
+    Global(GlobalDatetime),
+}
+
+impl DatetimeValue {
+    /// Parses `s` in the format its shape calls for, and fails with that format's error if it
+    /// isn't valid:
+    ///
+    /// - A `T` or a space makes it a datetime, which is global if the time is followed by `Z`,
+    ///   `+` or `-`.
+    /// - Otherwise, a `:` makes it a time and a `W` a week.
+    /// - Otherwise, it's a yearless date if it starts with `--` or its first field has two
+    ///   characters and is followed by only one more, a month if it has two fields, and a date if
+    ///   it has any other number of them.
+    pub fn parse(s: &str) -> Result<DatetimeValue, DateTimeParseError> {
+        if let Some(time_start) = s.find(['T', ' ']) {
+            if s[time_start..].contains(['Z', '+', '-']) {
+                return Ok(DatetimeValue::Global(s.parse()?));
+            }
+            return Ok(DatetimeValue::Local(s.parse()?));
+        }
+        if s.contains(':') {
+            return Ok(DatetimeValue::Time(s.parse()?));
+        }
+        if s.contains('W') {
+            return Ok(DatetimeValue::Week(s.parse()?));
+        }
+
+        let fields: Vec<&str> = s.split('-').collect();
+        Ok(match fields[..] {
+            ["", "", ..] => DatetimeValue::Yearless(s.parse()?),
+            [month, _] if month.len() == 2 => DatetimeValue::Yearless(s.parse()?),
+            [_, _] => DatetimeValue::Month(s.parse()?),
+            _ => DatetimeValue::Date(s.parse()?),
+        })
+    }
+}
+
+impl FromStr for DatetimeValue {
+    type Err = DateTimeParseError;
+
+    /// Same as [`DatetimeValue::parse`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        DatetimeValue::parse(s)
+    }
+}
+
+impl fmt::Display for DatetimeValue {
+    /// Writes the value in its own format.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DatetimeValue::Date(value) => value.fmt(f),
+            DatetimeValue::Month(value) => value.fmt(f),
+            DatetimeValue::Week(value) => value.fmt(f),
+            DatetimeValue::Yearless(value) => value.fmt(f),
+            DatetimeValue::Time(value) => value.fmt(f),
+            DatetimeValue::Local(value) => value.fmt(f),
+            DatetimeValue::Global(value) => value.fmt(f),
+        }
+    }
+}
+
+impl Datetime {
+    /// Returns the time left until `24:00:00` of the same day, i.e. until the next midnight.
+    ///
+    /// The value is interpreted in its implicit local time zone, so transitions such as daylight
+    /// saving time aren't taken into account.
+    pub fn until_end_of_day(&self) -> Duration {
+        let elapsed_millis =
+            (u64::from(self.time.hour.0) * 3600 + u64::from(self.time.minute.0) * 60) * 1000
+                + u64::from(self.time.second.millis());
+
+        Duration::from_millis(SECONDS_PER_DAY as u64 * 1000 - elapsed_millis)
+    }
+}
+
+/// A span of time that may be negative, such as the difference between two datetimes.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Default)]
+pub struct SignedDuration {
+    nanos: i128,
+}
+
+impl SignedDuration {
+    pub const ZERO: SignedDuration = SignedDuration { nanos: 0 };
+
+    pub fn from_nanos(nanos: i128) -> Self {
+        SignedDuration { nanos }
+    }
+
+    pub fn as_nanos(&self) -> i128 {
+        self.nanos
+    }
+
+    pub fn as_secs_f64(&self) -> f64 {
+        self.nanos as f64 / 1e9
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.nanos < 0
+    }
+
+    /// Returns the length of the span regardless of its direction.
+    pub fn unsigned_abs(&self) -> Duration {
+        let nanos = self.nanos.unsigned_abs();
+        let whole = NANOS_PER_SECOND as u128;
+
+        Duration::new((nanos / whole) as u64, (nanos % whole) as u32)
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(duration: Duration) -> Self {
+        SignedDuration {
+            nanos: duration.as_nanos() as i128,
+        }
+    }
+}
+
+impl Datetime {
+    /// Adds `duration`, carrying into the other components. Returns `None` if the result isn't
+    /// representable. Any part of the result finer than a millisecond is dropped.
+    pub fn checked_add(&self, duration: Duration) -> Option<Datetime> {
+        let nanos = i128::try_from(duration.as_nanos()).ok()?;
+        Self::from_nanos_since_epoch(self.nanos_since_epoch().checked_add(nanos)?)
+    }
+
+    /// Subtracts `duration`, borrowing from the other components. Returns `None` if the result
+    /// isn't representable.
+    pub fn checked_sub(&self, duration: Duration) -> Option<Datetime> {
+        let nanos = i128::try_from(duration.as_nanos()).ok()?;
+        Self::from_nanos_since_epoch(self.nanos_since_epoch().checked_sub(nanos)?)
+    }
+
+    /// Returns a copy with the seconds set to zero.
+    pub fn truncate_to_minute(&self) -> Datetime {
+        self.with_time(HourMinuteSecond {
+            second: Second::new(0),
+            ..self.time
+        })
+    }
+
+    /// Returns a copy with the minutes and seconds set to zero.
+    pub fn truncate_to_hour(&self) -> Datetime {
+        self.with_time(HourMinuteSecond {
+            minute: Minute(0),
+            second: Second::new(0),
+            ..self.time
+        })
+    }
+
+    /// Returns a copy at midnight of the same day.
+    pub fn truncate_to_day(&self) -> Datetime {
+        self.with_time(HourMinuteSecond::MIDNIGHT)
+    }
+
+    /// Returns the closest whole minute, rounding half a minute up. Returns `None` if that's past
+    /// [`Datetime::MAX`].
+    ///
+    /// ```
+    /// use html_datetime_local::Datetime;
+    ///
+    /// let dt: Datetime = "2023-12-18T12:34:56".parse().unwrap();
+    /// assert_eq!(
+    ///     dt.round_to_nearest_minute().unwrap().to_string(),
+    ///     "2023-12-18T12:35:00"
+    /// );
+    /// ```
+    pub fn round_to_nearest_minute(&self) -> Option<Datetime> {
+        let truncated = self.truncate_to_minute();
+        if self.time.second.millis < 30_000 {
+            return Some(truncated);
+        }
+
+        truncated.checked_add(Duration::from_secs(60))
+    }
+
+    /// Returns the number of whole seconds since `1970-01-01T00:00:00`, treating the value as
+    /// UTC. Earlier datetimes give negative timestamps, and fractional seconds are rounded down.
+    pub fn to_unix_timestamp(&self) -> i64 {
+        self.nanos_since_epoch().div_euclid(NANOS_PER_SECOND) as i64
+    }
+
+    /// Returns the current time in UTC according to the system clock, with millisecond precision.
+    ///
+    /// # Panics
+    ///
+    /// If the system clock is set outside of the range from [`Datetime::MIN`] to
+    /// [`Datetime::MAX`].
+    #[cfg(feature = "std")]
+    pub fn now_utc() -> Datetime {
+        let nanos = match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(since_epoch) => since_epoch.as_nanos() as i128,
+            Err(err) => -(err.duration().as_nanos() as i128),
+        };
+
+        Self::from_nanos_since_epoch(nanos).expect("The system clock is within the supported years")
+    }
+
+    /// Inverse of [`Datetime::to_unix_timestamp`]. Fails if the result is before year 1 or after
+    /// [`Datetime::MAX`].
+    pub fn from_unix_timestamp(secs: i64) -> Result<Datetime, DateTimeParseError> {
+        Self::from_nanos_since_epoch(i128::from(secs) * NANOS_PER_SECOND).ok_or_else(|| {
+            DateTimeParseError {
+                component: Component::Year,
+                found: secs.to_string(),
+                kind: DateTimeParseErrorKind::OutOfRange {
+                    min: Year::MIN,
+                    max: Year::MAX,
+                },
+                position: None,
+            }
         })
     }
+
+    /// Returns the time elapsed from `earlier` until `self`, which is negative if `earlier` is
+    /// actually later.
+    pub fn duration_since(&self, earlier: &Datetime) -> SignedDuration {
+        SignedDuration::from_nanos(self.nanos_since_epoch() - earlier.nanos_since_epoch())
+    }
+
+    /// Whether `self` and `other` are at most `epsilon_secs` seconds apart. Seconds are stored
+    /// with millisecond precision, so `PartialEq` is exact and this is only needed to tolerate
+    /// larger differences, e.g. from rounding done elsewhere.
+    pub fn approx_eq(&self, other: &Datetime, epsilon_secs: f32) -> bool {
+        self.duration_since(other).as_secs_f64().abs() <= f64::from(epsilon_secs)
+    }
+}
+
+impl Add<Duration> for Datetime {
+    type Output = Datetime;
+
+    /// # Panics
+    ///
+    /// Panics if the result isn't representable. Use [`Datetime::checked_add`] to handle that.
+    fn add(self, duration: Duration) -> Datetime {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to datetime")
+    }
+}
+
+impl Sub<Duration> for Datetime {
+    type Output = Datetime;
+
+    /// # Panics
+    ///
+    /// Panics if the result isn't representable. Use [`Datetime::checked_sub`] to handle that.
+    fn sub(self, duration: Duration) -> Datetime {
+        self.checked_sub(duration)
+            .expect("overflow when subtracting duration from datetime")
+    }
+}
+
+impl Sub<Datetime> for Datetime {
+    type Output = SignedDuration;
+
+    fn sub(self, other: Datetime) -> SignedDuration {
+        self.duration_since(&other)
+    }
 }
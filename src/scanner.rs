@@ -0,0 +1,146 @@
+//! The primitives the parsers scan their input with. They check the shape of the input, e.g. that
+//! a component consists of two digits, and leave turning it into values to the callers.
+
+use crate::{BorrowedDateTimeParseError, Component, DateTimeParseErrorKind, NumberError};
+
+// Splits off the leading run of at most `max` ASCII digits from `input`, returning the digits and
+// the rest.
+pub(crate) fn split_digits(input: &str, max: usize) -> (&str, &str) {
+    let len = input
+        .bytes()
+        .take(max)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    input.split_at(len)
+}
+
+// Reports an empty component, such as the month of `2023--18`, as missing rather than as an
+// invalid number.
+pub(crate) fn non_empty(
+    value: &str,
+    component: Component,
+) -> Result<&str, BorrowedDateTimeParseError<'_>> {
+    if value.is_empty() {
+        return Err(BorrowedDateTimeParseError {
+            component,
+            found: "",
+            kind: DateTimeParseErrorKind::Missing,
+            position: Some(0),
+        });
+    }
+
+    Ok(value)
+}
+
+// Checks that `value` contains nothing but ASCII digits, so that e.g. a sign or Arabic-Indic digits
+// get a clear error. An empty `value` passes.
+pub(crate) fn ascii_digits(
+    value: &str,
+    component: Component,
+) -> Result<&str, BorrowedDateTimeParseError<'_>> {
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(BorrowedDateTimeParseError {
+            component,
+            found: value,
+            kind: DateTimeParseErrorKind::InvalidNumber(NumberError::NotAsciiDigits),
+            position: Some(0),
+        });
+    }
+
+    Ok(value)
+}
+
+// Checks that `value` consists of at least four ASCII digits, as HTML requires of years.
+pub(crate) fn year_digits(value: &str) -> Result<&str, BorrowedDateTimeParseError<'_>> {
+    if ascii_digits(value, Component::Year)?.len() < 4 {
+        return Err(BorrowedDateTimeParseError {
+            component: Component::Year,
+            found: value,
+            kind: DateTimeParseErrorKind::InvalidNumber(NumberError::TooFewYearDigits),
+            position: Some(0),
+        });
+    }
+
+    Ok(value)
+}
+
+// Checks that `value` consists of exactly two ASCII digits, as HTML requires of most components.
+pub(crate) fn two_digits(
+    value: &str,
+    component: Component,
+) -> Result<&str, BorrowedDateTimeParseError<'_>> {
+    if ascii_digits(value, component)?.len() != 2 {
+        return Err(BorrowedDateTimeParseError {
+            component,
+            found: value,
+            kind: DateTimeParseErrorKind::InvalidNumber(NumberError::NotTwoDigits),
+            position: Some(0),
+        });
+    }
+
+    Ok(value)
+}
+
+// Returns the length of the longest prefix of `value` that is shaped like `HH:MM[:SS[.fff]]`,
+// without checking the number or kind of digits, so that non-ASCII digits are reported by the
+// component they're in. Anything after it can't be part of a time.
+pub(crate) fn time_len(value: &str, allow_comma_decimal: bool) -> usize {
+    let decimal: &[char] = if allow_comma_decimal {
+        &['.', ',']
+    } else {
+        &['.']
+    };
+
+    let mut rest = value.trim_start_matches(char::is_numeric);
+    for separator in [&[':'][..], &[':'], decimal] {
+        match rest.strip_prefix(separator) {
+            Some(after) => rest = after.trim_start_matches(char::is_numeric),
+            None => break,
+        }
+    }
+
+    value.len() - rest.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_digits() {
+        assert_eq!(split_digits("2023-12", usize::MAX), ("2023", "-12"));
+        assert_eq!(split_digits("12345", 2), ("12", "345"));
+        assert_eq!(split_digits("-12", 2), ("", "-12"));
+        assert_eq!(split_digits("", 2), ("", ""));
+        // Non-ASCII digits aren't digits as far as the grammar is concerned.
+        assert_eq!(split_digits("1٢", 2), ("1", "٢"));
+    }
+
+    #[test]
+    fn checks_component_digits() {
+        assert_eq!(non_empty("12", Component::Month), Ok("12"));
+        assert!(matches!(
+            non_empty("", Component::Month).unwrap_err().kind,
+            DateTimeParseErrorKind::Missing
+        ));
+
+        assert_eq!(ascii_digits("", Component::Day), Ok(""));
+        assert_eq!(ascii_digits("09", Component::Day), Ok("09"));
+        let err = ascii_digits("+9", Component::Day).unwrap_err();
+        assert_eq!((err.component, err.found), (Component::Day, "+9"));
+
+        assert_eq!(two_digits("09", Component::Hour), Ok("09"));
+        for value in ["9", "009", "", "0x"] {
+            assert!(two_digits(value, Component::Hour).is_err(), "{value}");
+        }
+
+        assert_eq!(year_digits("0001"), Ok("0001"));
+        assert_eq!(year_digits("275760"), Ok("275760"));
+        assert!(year_digits("999").is_err());
+
+        assert_eq!(time_len("12:34:56.789Z", false), 12);
+        assert_eq!(time_len("12:34:56,789", false), 8);
+        assert_eq!(time_len("12:34:56,789", true), 12);
+        assert_eq!(time_len("12:34 ", false), 5);
+    }
+}
@@ -0,0 +1,83 @@
+//! `Serialize` and `Deserialize` for [`Datetime`] using its string representation, and for
+//! [`StructuredDatetime`] using its components.
+
+use crate::{DateTimeParseError, Datetime};
+use core::fmt;
+use core::str::FromStr;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+impl Serialize for Datetime {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Datetime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(DatetimeVisitor)
+    }
+}
+
+struct DatetimeVisitor;
+
+impl Visitor<'_> for DatetimeVisitor {
+    type Value = Datetime;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a local date and time string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Datetime, E> {
+        Datetime::from_str(value).map_err(E::custom)
+    }
+}
+
+/// Wraps a [`Datetime`] to (de)serialize it as a struct of its components, such as
+/// `{"year":2023,"month":12,"day":18,"hour":12,"minute":34,"second":56.5}`, instead of a string.
+/// The second may be left out when deserializing, in which case it's zero.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "Components", into = "Components")]
+pub struct StructuredDatetime(pub Datetime);
+
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Components {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    #[serde(default)]
+    second: f32,
+}
+
+impl TryFrom<Components> for StructuredDatetime {
+    type Error = DateTimeParseError;
+
+    fn try_from(value: Components) -> Result<Self, Self::Error> {
+        Datetime::new(
+            value.year,
+            value.month,
+            value.day,
+            value.hour,
+            value.minute,
+            value.second,
+        )
+        .map(StructuredDatetime)
+    }
+}
+
+impl From<StructuredDatetime> for Components {
+    fn from(StructuredDatetime(datetime): StructuredDatetime) -> Self {
+        let (date, time) = datetime.into_parts();
+        Components {
+            year: date.year(),
+            month: date.month(),
+            day: date.day(),
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+        }
+    }
+}
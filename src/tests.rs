@@ -1,5 +1,7 @@
 use crate::*;
 use proptest::{prop_assert_eq, proptest};
+#[cfg(not(feature = "std"))]
+use std::prelude::v1::*;
 #[test]
 fn parse_valid_datetime() {
     let datetime_str = "2023-12-18T12:34:56";
@@ -12,8 +14,10 @@ fn parse_valid_datetime() {
         time: HourMinuteSecond {
             hour: Hour(12),
             minute: Minute(34),
-            second: Second(56.0),
+            second: Second::new(56_000),
         },
+        separator: Separator::T,
+        expanded_year_digits: None,
     };
 
     let parsed_datetime: Datetime = datetime_str
@@ -52,6 +56,7 @@ fn parse_invalid_datetime_invalid_year() {
             component: Component::Year,
             found,
             kind: DateTimeParseErrorKind::InvalidNumber(_),
+            ..
         } if found == "anno_domini"
     ));
 }
@@ -68,6 +73,7 @@ fn parse_invalid_datetime_invalid_month() {
             component: Component::Month,
             found,
             kind: DateTimeParseErrorKind::OutOfRange { .. },
+            ..
         } if found == "15"
     ));
 }
@@ -84,6 +90,7 @@ fn parse_invalid_datetime_february_29_nonleap() {
             component: Component::Day,
             found,
             kind: DateTimeParseErrorKind::OutOfRange { .. },
+            ..
         } if found == "29"
     ));
 }
@@ -96,6 +103,37 @@ fn parse_valid_datetime_february_29_leap() {
     assert!(result.is_ok());
 }
 
+#[test]
+fn display_valid_datetime() {
+    let dt: Datetime = "2023-12-18T12:34:56".parse().unwrap();
+
+    assert_eq!(dt.to_string(), "2023-12-18T12:34:56");
+}
+
+#[test]
+fn display_pads_components() {
+    let dt = Datetime {
+        date: YearMonthDay::from_components(Year(99), Month(1), Day(2)).unwrap(),
+        time: HourMinuteSecond {
+            hour: Hour(3),
+            minute: Minute(4),
+            second: Second::new(0),
+        },
+        separator: Separator::T,
+        expanded_year_digits: None,
+    };
+
+    assert_eq!(dt.to_string(), "0099-01-02T03:04:00");
+}
+
+#[test]
+fn display_fractional_second() {
+    let dt: Datetime = "2023-12-18T12:34:56.250".parse().unwrap();
+
+    assert_eq!(dt.to_string(), "2023-12-18T12:34:56.250");
+    assert_eq!((dt + Duration::ZERO).to_string(), "2023-12-18T12:34:56.25");
+}
+
 proptest! {
 
     #[test]
@@ -104,16 +142,18 @@ proptest! {
     }
 
     #[test]
-    fn parses_date_back_to_original_with_second(y in 0i32..10000,
-                                    m in 1u8..=12, d in 1u8..=28, h in 0u8..=23, min in 0u8..=59, sec in 0f32..=59.9f32) {
-        let s = format!("{y}-{m}-{d}T{h}:{min}:{sec}");
+    fn parses_date_back_to_original_with_second(y in 1i32..10000,
+                                    m in 1u8..=12, d in 1u8..=28, h in 0u8..=23, min in 0u8..=59, sec in 0u16..60000) {
+        let s = format!("{y:04}-{m:02}-{d:02}T{h:02}:{min:02}:{:02}.{:03}", sec / 1000, sec % 1000);
         let original = Datetime {
             date: YearMonthDay::from_components(y.try_into().unwrap(), m.try_into().unwrap(), d.try_into().unwrap()).unwrap(),
             time: HourMinuteSecond {
                 hour: h.try_into().unwrap(),
                 minute: min.try_into().unwrap(),
-                second: sec.try_into().unwrap(),
-            }
+                second: (f32::from(sec) / 1000.0).try_into().unwrap(),
+            },
+            separator: Separator::T,
+            expanded_year_digits: None,
         };
         let result: Result<Datetime, _> = s.parse();
         let dt = result.unwrap();
@@ -121,7 +161,7 @@ proptest! {
     }
 
     #[test]
-    fn parses_date_back_to_original_without_second(y in 0i32..10000,
+    fn parses_date_back_to_original_without_second(y in 1i32..10000,
                                     m in 1u8..=12, d in 1u8..=28, h in 0u8..=23, min in 0u8..=59) {
         let original = Datetime {
             date: YearMonthDay::from_components(y.try_into().unwrap(), m.try_into().unwrap(), d.try_into().unwrap()).unwrap(),
@@ -129,11 +169,2850 @@ proptest! {
                 hour: h.try_into().unwrap(),
                 minute: min.try_into().unwrap(),
                 second: 0f32.try_into().unwrap(),
-            }
+            },
+            separator: Separator::T,
+            expanded_year_digits: None,
         };
-        let s = format!("{y}-{m}-{d}T{h}:{min}");
+        let s = format!("{y:04}-{m:02}-{d:02}T{h:02}:{min:02}");
         let result: Result<Datetime, _> = s.parse();
         let dt = result.unwrap();
         prop_assert_eq!(original, dt);
     }
+
+    #[test]
+    fn display_round_trips(y in 1i32..10000, m in 1u8..=12, d in 1u8..=28, h in 0u8..=23,
+                           min in 0u8..=59, sec in 0u8..=59, millis in 0u16..1000) {
+        let mut s = format!("{y:04}-{m:02}-{d:02}T{h:02}:{min:02}:{sec:02}");
+        if millis != 0 {
+            s += format!(".{millis:03}").trim_end_matches('0');
+        }
+        let dt: Datetime = s.parse().unwrap();
+        prop_assert_eq!(dt.to_string(), s);
+    }
+
+    #[test]
+    fn display_then_parse_is_identity(y in 1i32..=Year::MAX, m in 1u8..=12, d in 1u8..=31,
+                                      h in 0u8..=23, min in 0u8..=59, millis in 0u16..60000,
+                                      with_seconds: bool) {
+        let Ok(date) = YearMonthDay::from_components(Year(y), Month(m), Day(d)) else {
+            return Ok(());
+        };
+        let second = if with_seconds { Second::new(millis) } else { Second::UNSPECIFIED };
+        let dt = Datetime::from_parts(
+            date,
+            HourMinuteSecond::from_components(Hour(h), Minute(min), second),
+        );
+
+        let reparsed: Datetime = dt.to_string().parse().unwrap();
+        prop_assert_eq!(&reparsed, &dt);
+        prop_assert_eq!(reparsed.to_string(), dt.to_string());
+        if !with_seconds {
+            let short: Datetime = format!("{}T{h:02}:{min:02}", dt.date).parse().unwrap();
+            prop_assert_eq!(short, dt);
+        }
+    }
+}
+
+#[test]
+fn recur_hourly_rolls_over_midnight() {
+    let start: Datetime = "2023-12-31T21:15:30".parse().unwrap();
+    let rule = RecurrenceRule {
+        every: Unit::Hour,
+        interval: 2,
+    };
+
+    let upcoming: Vec<Datetime> = start.recur(rule).take(3).collect();
+
+    let expected: Vec<Datetime> = [
+        "2023-12-31T23:15:30",
+        "2024-01-01T01:15:30",
+        "2024-01-01T03:15:30",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+    assert_eq!(upcoming, expected);
+}
+
+#[test]
+fn recur_monthly_clamps_day_without_drifting() {
+    let start: Datetime = "2023-12-31T08:00:00".parse().unwrap();
+    let rule = RecurrenceRule {
+        every: Unit::Month,
+        interval: 1,
+    };
+
+    let upcoming: Vec<Datetime> = start.recur(rule).take(4).collect();
+
+    let expected: Vec<Datetime> = [
+        "2024-01-31T08:00:00",
+        "2024-02-29T08:00:00",
+        "2024-03-31T08:00:00",
+        "2024-04-30T08:00:00",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+    assert_eq!(upcoming, expected);
+}
+
+#[test]
+fn recur_with_zero_interval_is_empty() {
+    let start: Datetime = "2023-12-31T08:00:00".parse().unwrap();
+    let rule = RecurrenceRule {
+        every: Unit::Day,
+        interval: 0,
+    };
+
+    assert_eq!(start.recur(rule).next(), None);
+}
+
+#[test]
+fn clamp_below_min_returns_min() {
+    let min: Datetime = "2023-12-01T09:00:00".parse().unwrap();
+    let max: Datetime = "2023-12-01T17:00:00".parse().unwrap();
+    let dt: Datetime = "2023-11-30T12:00:00".parse().unwrap();
+
+    assert_eq!(dt.clamp(&min, &max), min);
+}
+
+#[test]
+fn clamp_within_range_returns_self() {
+    let min: Datetime = "2023-12-01T09:00:00".parse().unwrap();
+    let max: Datetime = "2023-12-01T17:00:00".parse().unwrap();
+    let dt: Datetime = "2023-12-01T12:30:15.5".parse().unwrap();
+
+    assert_eq!(dt.clone().clamp(&min, &max), dt);
+}
+
+#[test]
+fn clamp_above_max_returns_max() {
+    let min: Datetime = "2023-12-01T09:00:00".parse().unwrap();
+    let max: Datetime = "2023-12-01T17:00:00".parse().unwrap();
+    let dt: Datetime = "2023-12-01T17:00:00.5".parse().unwrap();
+
+    assert_eq!(dt.clamp(&min, &max), max);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic]
+fn clamp_with_inverted_bounds_panics() {
+    let min: Datetime = "2023-12-01T17:00:00".parse().unwrap();
+    let max: Datetime = "2023-12-01T09:00:00".parse().unwrap();
+    let dt: Datetime = "2023-12-01T12:00:00".parse().unwrap();
+
+    dt.clamp(&min, &max);
+}
+
+#[test]
+fn parse_from_format_iso_layout() {
+    let dt = Datetime::parse_from_format("2023-12-18 12:34:56.5", "%Y-%m-%d %H:%M:%S").unwrap();
+
+    assert_eq!(dt, "2023-12-18T12:34:56.5".parse().unwrap());
+}
+
+#[test]
+fn parse_from_format_non_iso_layout() {
+    let dt = Datetime::parse_from_format("18/12/2023 at 9h05", "%d/%m/%Y at %Hh%M").unwrap();
+
+    assert_eq!(dt, "2023-12-18T09:05:00".parse().unwrap());
+}
+
+#[test]
+fn parse_from_format_validates_components() {
+    let result = Datetime::parse_from_format("29.02.2023 10:00", "%d.%m.%Y %H:%M");
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Day,
+            kind: DateTimeParseErrorKind::OutOfRange { .. },
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parse_from_format_rejects_unsupported_specifier() {
+    let result = Datetime::parse_from_format("Mon 2023-12-18 10:00", "%a %Y-%m-%d %H:%M");
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Format,
+            found,
+            kind: DateTimeParseErrorKind::UnsupportedSpecifier,
+            ..
+        } if found == "%a"
+    ));
+}
+
+#[test]
+fn parse_from_format_rejects_mismatched_literal() {
+    let result = Datetime::parse_from_format("2023/12/18 10:00", "%Y-%m-%d %H:%M");
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Format,
+            kind: DateTimeParseErrorKind::UnexpectedCharacters,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parse_from_format_requires_date_and_time_specifiers() {
+    let result = Datetime::parse_from_format("2023-12-18", "%Y-%m-%d");
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Hour,
+            kind: DateTimeParseErrorKind::Missing,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn is_midnight_without_seconds() {
+    let dt: Datetime = "2023-12-18T00:00".parse().unwrap();
+
+    assert!(dt.is_midnight());
+    assert!(!dt.is_noon());
+}
+
+#[test]
+fn is_midnight_rejects_fractional_second() {
+    let dt: Datetime = "2023-12-18T00:00:00.001".parse().unwrap();
+
+    assert!(!dt.is_midnight());
+}
+
+#[test]
+fn is_noon_without_seconds() {
+    let dt: Datetime = "2023-12-18T12:00".parse().unwrap();
+
+    assert!(dt.is_noon());
+    assert!(!dt.is_midnight());
+}
+
+#[test]
+fn week_of_month_first_week() {
+    // December 2023 starts on a Friday.
+    let date: YearMonthDay = "2023-12-03".parse().unwrap();
+
+    assert_eq!(date.week_of_month(Weekday::Monday), 1);
+    assert_eq!(date.week_of_month(Weekday::Sunday), 2);
+}
+
+#[test]
+fn week_of_month_last_week() {
+    let date: YearMonthDay = "2023-12-31".parse().unwrap();
+
+    assert_eq!(date.week_of_month(Weekday::Monday), 5);
+    assert_eq!(date.week_of_month(Weekday::Sunday), 6);
+}
+
+#[test]
+fn week_of_month_month_starting_on_first_day_of_week() {
+    // January 2024 starts on a Monday.
+    let date: YearMonthDay = "2024-01-07".parse().unwrap();
+
+    assert_eq!(date.week_of_month(Weekday::Monday), 1);
+    assert_eq!(date.week_of_month(Weekday::Sunday), 2);
+}
+
+#[test]
+fn fixed19_round_trip() {
+    let dt: Datetime = "2004-02-29T01:02:03".parse().unwrap();
+
+    let bytes = dt.to_fixed19().unwrap();
+
+    assert_eq!(&bytes, b"2004-02-29T01:02:03");
+    assert_eq!(Datetime::from_fixed19(&bytes).unwrap(), dt);
+}
+
+#[test]
+fn fixed19_drops_fractional_second() {
+    let dt: Datetime = "2023-12-18T12:34:56.789".parse().unwrap();
+
+    let bytes = dt.to_fixed19().unwrap();
+
+    assert_eq!(&bytes, b"2023-12-18T12:34:56");
+    assert_eq!(
+        Datetime::from_fixed19(&bytes).unwrap(),
+        "2023-12-18T12:34:56".parse().unwrap()
+    );
+}
+
+#[test]
+fn fixed19_needs_a_four_digit_year() {
+    let last: Datetime = "9999-12-31T23:59:59".parse().unwrap();
+    assert_eq!(&last.to_fixed19().unwrap(), b"9999-12-31T23:59:59");
+
+    let dt: Datetime = "10000-01-01T00:00".parse().unwrap();
+    assert_eq!(dt.to_fixed19(), None);
+    let dt: Datetime = "10000-12-31T23:59:59".parse().unwrap();
+    assert_eq!(dt.to_fixed19(), None);
+    assert_eq!(Datetime::MAX.to_fixed19(), None);
+}
+
+#[test]
+fn fixed19_rejects_non_ascii_byte() {
+    let mut bytes = *b"2023-12-18T12:34:56";
+    bytes[6] = 0xC3;
+
+    let result = Datetime::from_fixed19(&bytes);
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Month,
+            kind: DateTimeParseErrorKind::InvalidNumber(_),
+            ..
+        }
+    ));
+}
+
+#[test]
+fn iter_step_crosses_midnight() {
+    let range = DatetimeRange {
+        start: "2023-12-31T22:00:00".parse().unwrap(),
+        end: "2024-01-01T03:00:00".parse().unwrap(),
+    };
+
+    let values: Vec<Datetime> = range.iter_step(Duration::from_secs(90 * 60)).collect();
+
+    let expected: Vec<Datetime> = [
+        "2023-12-31T22:00:00",
+        "2023-12-31T23:30:00",
+        "2024-01-01T01:00:00",
+        "2024-01-01T02:30:00",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn iter_step_carries_fractional_seconds() {
+    let range = DatetimeRange {
+        start: "2023-12-18T12:34:59.5".parse().unwrap(),
+        end: "2023-12-18T12:35:01".parse().unwrap(),
+    };
+
+    let values: Vec<Datetime> = range.iter_step(Duration::from_millis(750)).collect();
+
+    let expected: Vec<Datetime> = ["2023-12-18T12:34:59.5", "2023-12-18T12:35:00.25"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn iter_step_zero_is_empty() {
+    let range = DatetimeRange {
+        start: "2023-12-18T12:00:00".parse().unwrap(),
+        end: "2023-12-18T13:00:00".parse().unwrap(),
+    };
+
+    assert_eq!(range.iter_step(Duration::ZERO).next(), None);
+}
+
+#[test]
+fn format_strips_trailing_fraction_zeros_by_default() {
+    let dt: Datetime = "2023-12-18T12:34:56.100".parse().unwrap();
+
+    assert_eq!(
+        dt.format(&FormatOptions::default()),
+        "2023-12-18T12:34:56.1"
+    );
+}
+
+#[test]
+fn format_keeps_trailing_fraction_zeros() {
+    let dt: Datetime = "2023-12-18T12:34:56.100".parse().unwrap();
+    let options = FormatOptions {
+        trailing_fraction_zeros: TrailingFractionZeros::Keep,
+        ..FormatOptions::default()
+    };
+
+    assert_eq!(dt.format(&options), "2023-12-18T12:34:56.100");
+}
+
+#[test]
+fn format_whole_second_has_no_fraction() {
+    let dt: Datetime = "2023-12-18T01:02:03".parse().unwrap();
+    let options = FormatOptions {
+        trailing_fraction_zeros: TrailingFractionZeros::Keep,
+        ..FormatOptions::default()
+    };
+
+    assert_eq!(dt.format(&options), "2023-12-18T01:02:03");
+    assert_eq!(dt.format(&FormatOptions::default()), "2023-12-18T01:02:03");
+}
+
+#[test]
+fn to_rfc2822_local_known_date() {
+    let dt: Datetime = "2023-12-18T12:34:56.789".parse().unwrap();
+
+    assert_eq!(dt.to_rfc2822_local(), "Mon, 18 Dec 2023 12:34:56");
+}
+
+#[test]
+fn to_rfc2822_local_single_digit_day() {
+    let dt: Datetime = "2000-01-01T00:00".parse().unwrap();
+
+    assert_eq!(dt.to_rfc2822_local(), "Sat, 1 Jan 2000 00:00:00");
+}
+
+#[test]
+fn is_already_normalized_accepts_canonical_input() {
+    assert!(is_already_normalized("2023-01-02T03:04").unwrap());
+    assert!(is_already_normalized("2023-01-02T03:04:05.6").unwrap());
+}
+
+#[test]
+fn is_already_normalized_rejects_under_padded_input() {
+    assert!(!is_already_normalized("2023-01-02T3:4").unwrap());
+    assert!(!is_already_normalized("2023-1-2T3:4").unwrap());
+    assert!(!is_already_normalized("2023-01-02T03:04:5").unwrap());
+}
+
+#[test]
+fn is_already_normalized_rejects_redundant_seconds() {
+    assert!(!is_already_normalized("2023-01-02T03:04:00").unwrap());
+    assert!(!is_already_normalized("2023-01-02T03:04:05.60").unwrap());
+}
+
+#[test]
+fn is_already_normalized_propagates_parse_errors() {
+    assert!(is_already_normalized("2023-00-02T03:04").is_err());
+}
+
+#[test]
+fn parse_valid_month_day() {
+    let month_day: MonthDay = "--12-18".parse().unwrap();
+
+    assert_eq!(
+        month_day,
+        MonthDay {
+            month: Month(12),
+            day: Day(18),
+        }
+    );
+    assert_eq!(month_day.to_string(), "--12-18");
+}
+
+#[test]
+fn parse_month_day_allows_february_29() {
+    let month_day: MonthDay = "--02-29".parse().unwrap();
+
+    assert_eq!(month_day.to_string(), "--02-29");
+}
+
+#[test]
+fn parse_invalid_month_day_out_of_range() {
+    let result: Result<MonthDay, _> = "--04-31".parse();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Day,
+            found,
+            kind: DateTimeParseErrorKind::OutOfRange { min: 1, max: 30 },
+            ..
+        } if found == "31"
+    ));
+}
+
+#[test]
+fn month_day_in_year() {
+    let month_day: MonthDay = "--02-29".parse().unwrap();
+
+    assert_eq!(
+        month_day.in_year(2004).unwrap(),
+        "2004-02-29".parse().unwrap()
+    );
+    assert!(matches!(
+        month_day.in_year(2023).unwrap_err(),
+        DateTimeParseError {
+            component: Component::Day,
+            kind: DateTimeParseErrorKind::OutOfRange { .. },
+            ..
+        }
+    ));
+}
+
+#[test]
+fn until_end_of_day_half_hour_before_midnight() {
+    let dt: Datetime = "2023-12-18T23:30:00".parse().unwrap();
+
+    assert_eq!(dt.until_end_of_day(), Duration::from_secs(30 * 60));
+}
+
+#[test]
+fn until_end_of_day_from_midnight_is_whole_day() {
+    let dt: Datetime = "2023-12-18T00:00".parse().unwrap();
+
+    assert_eq!(dt.until_end_of_day(), Duration::from_secs(24 * 3600));
+}
+
+#[test]
+fn until_end_of_day_with_fractional_second() {
+    let dt: Datetime = "2023-12-18T23:59:59.5".parse().unwrap();
+
+    assert_eq!(dt.until_end_of_day(), Duration::from_millis(500));
+}
+
+#[test]
+fn add_duration_rolls_over_year() {
+    let dt: Datetime = "2023-12-31T23:59:59".parse().unwrap();
+
+    assert_eq!(
+        dt + Duration::from_secs(1),
+        "2024-01-01T00:00:00".parse().unwrap()
+    );
+}
+
+#[test]
+fn sub_duration_borrows_across_leap_day() {
+    let dt: Datetime = "2004-03-01T00:00:00.25".parse().unwrap();
+
+    assert_eq!(
+        dt - Duration::from_millis(500),
+        "2004-02-29T23:59:59.75".parse().unwrap()
+    );
+}
+
+#[test]
+fn checked_add_overflows_past_last_year() {
+    let dt: Datetime = "275760-12-31T23:59:59".parse().unwrap();
+
+    assert_eq!(dt.checked_add(Duration::from_secs(1)), None);
+}
+
+#[test]
+fn checked_sub_overflows_before_first_year() {
+    let dt = Datetime {
+        date: YearMonthDay::from_components(Year(1), Month(1), Day(1)).unwrap(),
+        time: "00:00:00".parse().unwrap(),
+        separator: Separator::T,
+        expanded_year_digits: None,
+    };
+
+    assert_eq!(dt.checked_sub(Duration::from_secs(1)), None);
+}
+
+#[test]
+#[should_panic]
+fn add_duration_panics_on_overflow() {
+    let _ = Datetime::MAX + Duration::from_secs(1);
+}
+
+#[test]
+fn sub_datetimes_is_signed() {
+    let earlier: Datetime = "2004-02-28T23:59:59".parse().unwrap();
+    let later: Datetime = "2004-03-01T00:00:00".parse().unwrap();
+
+    let forward = later.clone() - earlier.clone();
+    let backward = earlier - later;
+
+    assert_eq!(forward.unsigned_abs(), Duration::from_secs(24 * 3600 + 1));
+    assert!(!forward.is_negative());
+    assert!(backward.is_negative());
+    assert_eq!(backward.as_nanos(), -forward.as_nanos());
+}
+
+#[test]
+fn display_year_month_day() {
+    let date: YearMonthDay = "2004-02-29".parse().unwrap();
+
+    assert_eq!(date.to_string(), "2004-02-29");
+}
+
+#[test]
+fn display_hour_minute_second() {
+    let time: HourMinuteSecond = "07:08:09".parse().unwrap();
+
+    assert_eq!(time.to_string(), "07:08:09");
+}
+
+#[test]
+fn display_datetime_is_date_and_time_joined() {
+    let dt: Datetime = "2004-02-29T07:08:09.5".parse().unwrap();
+
+    assert_eq!(dt.to_string(), format!("{}T{}", dt.date, dt.time));
+}
+
+#[test]
+fn parse_time_without_second() {
+    let time: HourMinuteSecond = "12:34".parse().unwrap();
+
+    assert_eq!(
+        time,
+        HourMinuteSecond {
+            hour: Hour(12),
+            minute: Minute(34),
+            second: Second::new(0),
+        }
+    );
+}
+
+#[test]
+fn parse_invalid_time_trailing_colon() {
+    let result: Result<HourMinuteSecond, _> = "12:34:".parse();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Second,
+            kind: DateTimeParseErrorKind::Missing,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parse_invalid_time_four_parts() {
+    let result: Result<Datetime, _> = "2023-12-18T12:34:56:78".parse();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Time,
+            kind: DateTimeParseErrorKind::UnexpectedCharacters,
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parse_fractional_second() {
+    for (input, expected) in [("12:34:56.5", 56_500), ("12:34:56.789", 56_789)] {
+        let time: HourMinuteSecond = input.parse().unwrap();
+
+        assert_eq!(time.second, Second::new(expected));
+        assert_eq!(time.to_string(), input);
+    }
+}
+
+#[test]
+fn parse_invalid_fractional_second() {
+    for input in ["12:34:56.", "12:34:56.1234"] {
+        let result: Result<HourMinuteSecond, _> = input.parse();
+
+        assert!(
+            matches!(
+                result.unwrap_err(),
+                DateTimeParseError {
+                    component: Component::Second,
+                    kind: DateTimeParseErrorKind::InvalidNumber(_),
+                    ..
+                }
+            ),
+            "{input}"
+        );
+    }
+
+    let err = "12:34:56.-1".parse::<HourMinuteSecond>().unwrap_err();
+    assert_eq!(err.component, Component::Time);
+    assert_eq!(err.found, "-1");
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::UnexpectedCharacters
+    ));
+}
+
+#[test]
+fn parse_invalid_second_out_of_range() {
+    let result: Result<HourMinuteSecond, _> = "12:34:60".parse();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Second,
+            kind: DateTimeParseErrorKind::OutOfRange { min: 0, max: 59 },
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parse_invalid_month_13_is_out_of_range() {
+    let result: Result<Datetime, _> = "2023-13-01T00:00".parse();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Month,
+            kind: DateTimeParseErrorKind::OutOfRange { min: 1, max: 12 },
+            ..
+        }
+    ));
+}
+
+#[test]
+fn parse_space_separated_datetime() {
+    let dt: Datetime = "2023-12-18 12:34:56".parse().unwrap();
+
+    assert_eq!(dt, "2023-12-18T12:34:56".parse().unwrap());
+    assert_eq!(dt.to_string(), "2023-12-18T12:34:56");
+}
+
+#[test]
+fn format_preserves_separator() {
+    let options = FormatOptions {
+        preserve_separator: true,
+        ..FormatOptions::default()
+    };
+
+    for input in ["2023-12-18 12:34:56", "2023-12-18T12:34:56"] {
+        let dt: Datetime = input.parse().unwrap();
+
+        assert_eq!(dt.format(&options), input);
+    }
+}
+
+#[test]
+fn parse_invalid_datetime_mixed_separators() {
+    for input in [
+        "2023-12-18T12:34:56 ",
+        "2023-12-18 T12:34:56",
+        "2023-12-18 12:34 56",
+        "2023-12-18T12:34T56",
+    ] {
+        let result: Result<Datetime, _> = input.parse();
+
+        assert!(
+            matches!(
+                result.unwrap_err(),
+                DateTimeParseError {
+                    kind: DateTimeParseErrorKind::UnexpectedCharacters,
+                    ..
+                }
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn parse_month_and_day_require_two_digits() {
+    for (digits, valid) in [("1", false), ("01", true), ("001", false), ("12", true)] {
+        for input in [format!("2023-{digits}-01"), format!("2023-01-{digits}")] {
+            let result: Result<YearMonthDay, _> = input.parse();
+
+            if valid {
+                assert!(result.is_ok(), "{input}");
+            } else {
+                assert!(
+                    matches!(
+                        result.unwrap_err(),
+                        DateTimeParseError {
+                            found,
+                            kind: DateTimeParseErrorKind::InvalidNumber(_),
+                            ..
+                        } if found == digits
+                    ),
+                    "{input}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn parse_invalid_datetime_single_digit_month() {
+    let result: Result<Datetime, _> = "2023-1-01T00:00".parse();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Month,
+            found,
+            kind: DateTimeParseErrorKind::InvalidNumber(_),
+            ..
+        } if found == "1"
+    ));
+}
+
+#[test]
+fn parse_year_requires_four_positive_digits() {
+    for (year, valid) in [
+        ("0", false),
+        ("23", false),
+        ("0000", false),
+        ("0023", true),
+        ("2023", true),
+        ("10000", true),
+    ] {
+        let result: Result<YearMonthDay, _> = format!("{year}-01-01").parse();
+
+        if valid {
+            assert!(result.is_ok(), "{year}");
+        } else {
+            assert!(
+                matches!(
+                    result.unwrap_err(),
+                    DateTimeParseError {
+                        component: Component::Year,
+                        ..
+                    }
+                ),
+                "{year}"
+            );
+        }
+    }
+}
+
+#[test]
+fn parse_invalid_year_zero_is_out_of_range() {
+    let result: Result<YearMonthDay, _> = "0000-01-01".parse();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Year,
+            found,
+            kind: DateTimeParseErrorKind::OutOfRange { min: 1, .. },
+            ..
+        } if found == "0000"
+    ));
+}
+
+#[test]
+fn parse_invalid_datetime_trailing_characters() {
+    for (input, rest) in [
+        ("2023-12-18T12:34:56Z", "Z"),
+        ("2023-12-18T12:34:56:78", ":78"),
+        ("2023-12-18T12:34:56.5+01:00", "+01:00"),
+        ("2023-12-18T12:34:56T", "T"),
+        ("2023-12-18 12:34 ", " "),
+        ("2023-12-18T12:34:56.-1", "-1"),
+    ] {
+        let result: Result<Datetime, _> = input.parse();
+
+        assert!(
+            matches!(
+                result.unwrap_err(),
+                DateTimeParseError {
+                    component: Component::Time,
+                    found,
+                    kind: DateTimeParseErrorKind::UnexpectedCharacters,
+                    ..
+                } if found == rest
+            ),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn parse_invalid_datetime_trailing_words() {
+    for (input, found, position) in [
+        ("2023-12-18T12:34:56 hello", " hello", 19),
+        ("2023-12-18T12:34 x", " x", 16),
+    ] {
+        let err = input.parse::<Datetime>().unwrap_err();
+        assert_eq!(
+            err.kind,
+            DateTimeParseErrorKind::UnexpectedCharacters,
+            "{input}"
+        );
+        assert_eq!(err.found, found, "{input}");
+        assert_eq!(err.position, Some(position), "{input}");
+        assert_eq!(&input[position..], found);
+    }
+
+    // A space separator that isn't allowed is reported from the space on as well.
+    let err: DateTimeParseError = DatetimeParser::new()
+        .allow_space_separator(false)
+        .parse_borrowed("2023-12-18 12:34")
+        .unwrap_err()
+        .into();
+    assert_eq!(
+        (err.kind, err.found.as_str(), err.position),
+        (
+            DateTimeParseErrorKind::UnexpectedCharacters,
+            " 12:34",
+            Some(10)
+        )
+    );
+}
+
+#[test]
+fn parse_invalid_date_extra_component() {
+    let result: Result<Datetime, _> = "2023-12-18-01T12:34:56".parse();
+
+    assert!(matches!(
+        result.unwrap_err(),
+        DateTimeParseError {
+            component: Component::Date,
+            found,
+            kind: DateTimeParseErrorKind::UnexpectedCharacters,
+            ..
+        } if found == "-01"
+    ));
+}
+
+#[test]
+fn sort_datetimes_chronologically() {
+    let sorted = [
+        "1999-12-31T23:59:59.999",
+        "2000-01-01T00:00",
+        "2000-01-01T00:00:00.001",
+        "2000-01-01T00:01",
+        "2000-01-01T01:00",
+        "2000-01-02T00:00",
+        "2000-02-01T00:00",
+        "2001-01-01T00:00",
+    ];
+    let mut datetimes: Vec<Datetime> = [5, 2, 7, 0, 3, 6, 1, 4]
+        .iter()
+        .map(|&i| sorted[i].parse().unwrap())
+        .collect();
+
+    datetimes.sort();
+
+    let expected: Vec<Datetime> = sorted.iter().map(|s| s.parse().unwrap()).collect();
+    assert_eq!(datetimes, expected);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let dt: Datetime = "2023-12-18T12:34:56.5".parse().unwrap();
+
+    let json = serde_json::to_string(&dt).unwrap();
+
+    assert_eq!(json, "\"2023-12-18T12:34:56.5\"");
+    assert_eq!(serde_json::from_str::<Datetime>(&json).unwrap(), dt);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_rejects_invalid_datetime() {
+    let result = serde_json::from_str::<Datetime>("\"2023-02-29T12:34:56\"");
+
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_round_trip_leap_day() {
+    let dt: Datetime = "2004-02-29T23:59:59.25".parse().unwrap();
+
+    let naive = chrono::NaiveDateTime::try_from(dt.clone()).unwrap();
+
+    assert_eq!(
+        naive,
+        chrono::NaiveDate::from_ymd_opt(2004, 2, 29)
+            .unwrap()
+            .and_hms_milli_opt(23, 59, 59, 250)
+            .unwrap()
+    );
+    assert_eq!(Datetime::try_from(naive).unwrap(), dt);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn chrono_rejects_year_zero() {
+    let naive = chrono::NaiveDate::from_ymd_opt(0, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    assert!(matches!(
+        Datetime::try_from(naive).unwrap_err(),
+        DateTimeParseError {
+            component: Component::Year,
+            kind: DateTimeParseErrorKind::OutOfRange { .. },
+            ..
+        }
+    ));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_round_trip_preserves_fraction() {
+    let dt: Datetime = "2023-12-31T23:59:59.999".parse().unwrap();
+
+    let primitive = time::PrimitiveDateTime::try_from(dt.clone()).unwrap();
+
+    assert_eq!(
+        primitive.date(),
+        time::Date::from_calendar_date(2023, time::Month::December, 31).unwrap()
+    );
+    assert_eq!(primitive.millisecond(), 999);
+    assert_eq!(Datetime::try_from(primitive).unwrap(), dt);
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn time_rejects_year_beyond_range() {
+    let dt: Datetime = "10000-01-01T00:00".parse().unwrap();
+
+    assert!(matches!(
+        time::PrimitiveDateTime::try_from(dt).unwrap_err(),
+        DateTimeParseError {
+            component: Component::Year,
+            kind: DateTimeParseErrorKind::OutOfRange { .. },
+            ..
+        }
+    ));
+}
+
+#[test]
+fn new_validates_components() {
+    assert_eq!(
+        Datetime::new(2023, 12, 18, 9, 5, 7.25).unwrap(),
+        Datetime::from_str("2023-12-18T09:05:07.25").unwrap()
+    );
+
+    for (args, component) in [
+        ((0, 1, 1, 0, 0, 0.0), Component::Year),
+        ((2023, 13, 1, 0, 0, 0.0), Component::Month),
+        ((2023, 2, 30, 0, 0, 0.0), Component::Day),
+        ((2023, 1, 1, 24, 0, 0.0), Component::Hour),
+        ((2023, 1, 1, 0, 60, 0.0), Component::Minute),
+        ((2023, 1, 1, 0, 0, 60.0), Component::Second),
+    ] {
+        let (year, month, day, hour, minute, second) = args;
+        let err = Datetime::new(year, month, day, hour, minute, second).unwrap_err();
+        assert_eq!(err.component, component);
+        assert!(matches!(
+            err.kind,
+            DateTimeParseErrorKind::OutOfRange { .. }
+        ));
+    }
+}
+
+#[test]
+fn accessors_expose_components() {
+    let dt = Datetime::from_str("0900-01-31 23:59:59.999").unwrap();
+
+    assert_eq!(dt.date().year(), 900);
+    assert_eq!(dt.date().month(), 1);
+    assert_eq!(dt.date().day(), 31);
+    assert_eq!(dt.time().hour(), 23);
+    assert_eq!(dt.time().minute(), 59);
+    assert_eq!(dt.time().second(), 59.999);
+}
+
+#[test]
+fn parses_year_month() {
+    let year_month: YearMonth = "2023-12".parse().unwrap();
+
+    assert_eq!((year_month.year(), year_month.month()), (2023, 12));
+    assert_eq!(year_month.to_string(), "2023-12");
+}
+
+#[test]
+fn year_month_rejects_out_of_range_month() {
+    let err = "2023-13".parse::<YearMonth>().unwrap_err();
+
+    assert_eq!(err.component, Component::Month);
+    assert_eq!(err.found, "13");
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::OutOfRange { min: 1, max: 12 }
+    ));
+}
+
+#[test]
+fn year_month_rejects_day() {
+    let err = "2023-12-01".parse::<YearMonth>().unwrap_err();
+
+    assert_eq!(err.component, Component::Date);
+    assert_eq!(err.found, "-01");
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::UnexpectedCharacters
+    ));
+}
+
+#[test]
+fn parses_year_week() {
+    let year_week: YearWeek = "2023-W07".parse().unwrap();
+
+    assert_eq!((year_week.year(), year_week.week()), (2023, 7));
+    assert_eq!(year_week.to_string(), "2023-W07");
+}
+
+#[test]
+fn year_week_accepts_week_53_only_in_long_years() {
+    // 2015 starts on a Thursday, 2020 is a leap year starting on a Wednesday.
+    for input in ["2015-W53", "2020-W53", "2021-W52"] {
+        assert!(input.parse::<YearWeek>().is_ok(), "{input}");
+    }
+
+    // 2021 starts on a Friday, 2019 is a common year starting on a Tuesday.
+    for input in ["2021-W53", "2019-W53", "2020-W54", "2020-W00"] {
+        let err = input.parse::<YearWeek>().unwrap_err();
+        assert_eq!(err.component, Component::Week, "{input}");
+        assert!(
+            matches!(err.kind, DateTimeParseErrorKind::OutOfRange { min: 1, .. }),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn year_week_requires_w_prefix() {
+    let err = "2023-07".parse::<YearWeek>().unwrap_err();
+
+    assert_eq!(err.component, Component::Week);
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::UnexpectedCharacters
+    ));
+}
+
+#[test]
+fn month_day_hyphens_are_optional() {
+    let month_day: MonthDay = "02-29".parse().unwrap();
+    assert_eq!(month_day, "--02-29".parse().unwrap());
+
+    let err = "02-30".parse::<MonthDay>().unwrap_err();
+    assert_eq!(err.component, Component::Day);
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::OutOfRange { min: 1, max: 29 }
+    ));
+
+    let err = "13-01".parse::<MonthDay>().unwrap_err();
+    assert_eq!(err.component, Component::Month);
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::OutOfRange { min: 1, max: 12 }
+    ));
+
+    assert!("-02-28".parse::<MonthDay>().is_err());
+}
+
+#[test]
+fn parses_global_datetime() {
+    for (input, minutes) in [
+        ("2023-12-18T12:34:56Z", 0),
+        ("2023-12-18T12:34:56+05:30", 330),
+        ("2023-12-18T12:34:56-08:00", -480),
+    ] {
+        let global: GlobalDatetime = input.parse().unwrap();
+
+        assert_eq!(global.datetime, "2023-12-18T12:34:56".parse().unwrap());
+        assert_eq!(global.offset.minutes(), minutes);
+        assert_eq!(global.to_string(), input);
+    }
+}
+
+#[test]
+fn global_datetime_rejects_invalid_offsets() {
+    for input in [
+        "2023-12-18T12:34:56+5:3",
+        "2023-12-18T12:34:56+24:00",
+        "2023-12-18T12:34:56-23:60",
+        "2023-12-18T12:34:56+530",
+        "2023-12-18T12:34:56+05300",
+        "2023-12-18T12:34:56+05",
+        "2023-12-18T12:34:56+05:",
+        "2023-12-18T12:34:56",
+    ] {
+        let err = input.parse::<GlobalDatetime>().unwrap_err();
+        assert_eq!(err.component, Component::Offset, "{input}");
+    }
+
+    assert!("2023-12-18T12:34:56-23:59"
+        .parse::<GlobalDatetime>()
+        .is_ok());
+}
+
+#[test]
+fn second_boundaries() {
+    assert_eq!("59.999".parse::<Second>().unwrap(), Second::new(59_999));
+
+    let err = "60".parse::<Second>().unwrap_err();
+    assert_eq!(err.found, "60");
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::OutOfRange { min: 0, max: 59 }
+    ));
+
+    // The literal rounds up to 60.0 in an f32.
+    assert!(Second::try_from(59.999_999_5_f32).is_err());
+
+    assert_eq!(Second::try_from(-0.0).unwrap(), Second::new(0));
+}
+
+#[test]
+fn second_rejects_non_finite_values() {
+    for input in ["inf", "-inf", "NaN", "infinity"] {
+        let err = input.parse::<Second>().unwrap_err();
+        assert_eq!(err.found, input);
+        assert!(
+            matches!(err.kind, DateTimeParseErrorKind::InvalidNumber(_)),
+            "{input}"
+        );
+    }
+
+    for value in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+        assert!(matches!(
+            Second::try_from(value).unwrap_err().kind,
+            DateTimeParseErrorKind::InvalidNumber(_)
+        ));
+    }
+}
+
+#[test]
+fn min_and_max_bound_parsed_values() {
+    for input in [
+        "0001-01-01T00:00",
+        "2023-12-18T12:34:56.789",
+        "275760-12-31T23:59:59.999",
+    ] {
+        let dt: Datetime = input.parse().unwrap();
+        assert!(Datetime::MIN <= dt && dt <= Datetime::MAX, "{input}");
+    }
+
+    assert_eq!(Datetime::MIN.to_string(), "0001-01-01T00:00:00");
+    assert_eq!(Datetime::MAX.to_string(), "275760-12-31T23:59:59.999");
+    assert!("275761-01-01T00:00".parse::<Datetime>().is_err());
+}
+
+#[test]
+fn duration_since_crosses_leap_day() {
+    let earlier: Datetime = "2004-02-28T23:59:59".parse().unwrap();
+    let later: Datetime = "2004-03-01T00:00:00".parse().unwrap();
+
+    assert_eq!(
+        later.duration_since(&earlier),
+        SignedDuration::from_nanos((24 * 3600 + 1) * 1_000_000_000)
+    );
+    assert_eq!(
+        earlier.duration_since(&later),
+        SignedDuration::from_nanos(-(24 * 3600 + 1) * 1_000_000_000)
+    );
+}
+
+#[test]
+fn duration_since_crosses_year_with_fraction() {
+    let earlier: Datetime = "2023-12-31T23:59:59.5".parse().unwrap();
+    let later: Datetime = "2024-01-01T00:00:00.25".parse().unwrap();
+
+    assert_eq!(later.duration_since(&earlier).as_secs_f64(), 0.75);
+    assert_eq!(later.duration_since(&later), SignedDuration::ZERO);
+}
+
+#[test]
+fn weekday_matches_known_dates() {
+    for (input, weekday) in [
+        ("2023-12-18", Weekday::Monday),
+        ("2000-01-01", Weekday::Saturday),
+        ("1970-01-01", Weekday::Thursday),
+        ("0001-01-01", Weekday::Monday),
+        ("2000-02-29", Weekday::Tuesday),
+        ("2004-02-29", Weekday::Sunday),
+        ("2004-03-01", Weekday::Monday),
+        ("1900-03-01", Weekday::Thursday),
+    ] {
+        let date: YearMonthDay = input.parse().unwrap();
+        assert_eq!(date.weekday(), weekday, "{input}");
+    }
+}
+
+#[test]
+fn ordinal_counts_days_of_year() {
+    for (input, ordinal) in [
+        ("2023-01-01", 1),
+        ("2023-12-31", 365),
+        ("2004-12-31", 366),
+        ("2004-03-01", 61),
+        ("2023-03-01", 60),
+    ] {
+        let date: YearMonthDay = input.parse().unwrap();
+        assert_eq!(date.ordinal(), ordinal, "{input}");
+    }
+}
+
+#[test]
+fn leap_years_follow_gregorian_rule() {
+    for (year, leap) in [
+        (1900, false),
+        (2000, true),
+        (2004, true),
+        (2023, false),
+        (2100, false),
+    ] {
+        assert_eq!(is_leap_year(year), leap, "{year}");
+        assert_eq!(Year::try_from(year).unwrap().is_leap(), leap, "{year}");
+    }
+}
+
+#[test]
+fn default_parser_matches_from_str() {
+    let parser = DatetimeParser::default();
+
+    for input in [
+        "2023-12-18T12:34:56.789",
+        "2023-12-18 12:34",
+        "2023-1-18T12:34",
+    ] {
+        assert_eq!(
+            parser.parse(input).ok(),
+            Datetime::from_str(input).ok(),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn parser_allows_single_digit_fields() {
+    let lenient = DatetimeParser::new().allow_single_digit_fields(true);
+
+    assert_eq!(
+        lenient.parse("2023-1-8T12:34").unwrap(),
+        Datetime::from_str("2023-01-08T12:34").unwrap()
+    );
+    assert_eq!(
+        lenient.parse("2023-12-18T12:34").unwrap(),
+        Datetime::from_str("2023-12-18T12:34").unwrap()
+    );
+    assert!(lenient.parse("2023-123-18T12:34").is_err());
+
+    let err = DatetimeParser::new().parse("2023-1-8T12:34").unwrap_err();
+    assert_eq!(err.component, Component::Month);
+}
+
+#[test]
+fn parser_can_reject_space_separator() {
+    assert!(DatetimeParser::new().parse("2023-12-18 12:34").is_ok());
+
+    let strict = DatetimeParser::new().allow_space_separator(false);
+    let err = strict.parse("2023-12-18 12:34").unwrap_err();
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::UnexpectedCharacters
+    ));
+    assert!(strict.parse("2023-12-18T12:34").is_ok());
+}
+
+#[test]
+fn parser_can_require_seconds() {
+    let parser = DatetimeParser::new().require_seconds(true);
+
+    let err = parser.parse("2023-12-18T12:34").unwrap_err();
+    assert_eq!(err.component, Component::Second);
+    assert!(matches!(err.kind, DateTimeParseErrorKind::Missing));
+    assert!(parser.parse("2023-12-18T12:34:00").is_ok());
+}
+
+#[test]
+fn parse_date_rejects_fourth_component() {
+    let err = "2023-12-18-01".parse::<YearMonthDay>().unwrap_err();
+
+    assert_eq!(err.component, Component::Date);
+    assert_eq!(err.found, "-01");
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::UnexpectedCharacters
+    ));
+}
+
+#[test]
+fn fractional_seconds_round_trip_exactly() {
+    for (input, millis) in [
+        ("2023-12-18T12:34:56.1", 56_100),
+        ("2023-12-18T12:34:56.12", 56_120),
+        ("2023-12-18T12:34:56.123", 56_123),
+        ("2023-12-18T12:34:56.789", 56_789),
+        ("2023-12-18T12:34:56.05", 56_050),
+    ] {
+        let dt: Datetime = input.parse().unwrap();
+
+        assert_eq!(dt.time.second, Second::new(millis), "{input}");
+        assert_eq!(dt.to_string(), input);
+    }
+}
+
+#[test]
+fn second_from_f32_rounds_to_milliseconds() {
+    assert_eq!(Second::try_from(56.789).unwrap(), Second::new(56_789));
+    assert_eq!(Second::try_from(0.1).unwrap().as_f32(), 0.1);
+    assert!(matches!(
+        Second::try_from(59.9996).unwrap_err().kind,
+        DateTimeParseErrorKind::OutOfRange { min: 0, max: 59 }
+    ));
+}
+
+#[test]
+fn iter_step_accumulates_sub_millisecond_steps() {
+    let range = DatetimeRange {
+        start: "2023-12-18T12:34:56".parse().unwrap(),
+        end: "2023-12-18T12:34:56.002".parse().unwrap(),
+    };
+
+    let values: Vec<Datetime> = range.iter_step(Duration::from_micros(500)).collect();
+
+    let expected: Vec<Datetime> = [
+        "2023-12-18T12:34:56",
+        "2023-12-18T12:34:56",
+        "2023-12-18T12:34:56.001",
+        "2023-12-18T12:34:56.001",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+    assert_eq!(values, expected);
+}
+
+#[test]
+fn equal_datetimes_hash_equally() {
+    use std::collections::HashSet;
+
+    let set: HashSet<Datetime> = [
+        "2023-12-18T12:34:56.5",
+        "2023-12-18 12:34:56.500",
+        "2023-12-18T12:34:56.50",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn parser_rolls_end_of_day_into_next_day() {
+    let parser = DatetimeParser::new().allow_end_of_day(true);
+
+    for (input, expected) in [
+        ("2023-12-31T24:00:00", "2024-01-01T00:00:00"),
+        ("2024-02-28T24:00", "2024-02-29T00:00"),
+        ("2023-02-28 24:00:00.000", "2023-03-01T00:00"),
+        ("2023-12-18T23:59:59", "2023-12-18T23:59:59"),
+    ] {
+        assert_eq!(
+            parser.parse(input).unwrap(),
+            expected.parse().unwrap(),
+            "{input}"
+        );
+    }
+
+    for input in [
+        "2023-12-31T24:00:01",
+        "2023-12-31T24:01",
+        "275760-12-31T24:00",
+        "2023-12-31T240:00",
+        "2023-12-31T2400:00",
+        "2023-12-31T24٣:00",
+    ] {
+        assert!(parser.parse(input).is_err(), "{input}");
+    }
+
+    let err = parser.parse("2023-12-31T240:00").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Hour, Some(11)));
+
+    let err = "2023-12-31T24:00:00".parse::<Datetime>().unwrap_err();
+    assert_eq!(err.component, Component::Hour);
+}
+
+#[test]
+fn to_unix_timestamp_counts_seconds_since_epoch() {
+    for (input, timestamp) in [
+        ("1970-01-01T00:00:00", 0),
+        ("2000-01-01T00:00:00", 946_684_800),
+        ("1969-12-31T23:59:59", -1),
+        ("1969-12-31T23:59:59.5", -1),
+        ("2004-02-29T12:00:00", 1_078_056_000),
+        ("0001-01-01T00:00:00", -62_135_596_800),
+    ] {
+        let dt: Datetime = input.parse().unwrap();
+        assert_eq!(dt.to_unix_timestamp(), timestamp, "{input}");
+    }
+}
+
+#[test]
+fn from_unix_timestamp_round_trips() {
+    for input in [
+        "1970-01-01T00:00:00",
+        "1969-12-31T23:59:59",
+        "2004-02-29T23:59:59",
+        "2000-03-01T00:00:00",
+        "0001-01-01T00:00:00",
+        "275760-12-31T23:59:59",
+    ] {
+        let dt: Datetime = input.parse().unwrap();
+        assert_eq!(
+            Datetime::from_unix_timestamp(dt.to_unix_timestamp()).unwrap(),
+            dt,
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn from_unix_timestamp_rejects_unrepresentable_years() {
+    let last = Datetime::MAX.to_unix_timestamp();
+    let first = Datetime::MIN.to_unix_timestamp();
+
+    for secs in [last + 1, first - 1, i64::MIN, i64::MAX] {
+        let err = Datetime::from_unix_timestamp(secs).unwrap_err();
+        assert_eq!(err.component, Component::Year, "{secs}");
+        assert!(matches!(
+            err.kind,
+            DateTimeParseErrorKind::OutOfRange { .. }
+        ));
+    }
+}
+
+#[test]
+fn checked_add_days_crosses_boundaries() {
+    for (input, days, expected) in [
+        ("2023-02-28", 1, "2023-03-01"),
+        ("2004-02-28", 1, "2004-02-29"),
+        ("2023-12-31", 1, "2024-01-01"),
+        ("2024-01-01", -1, "2023-12-31"),
+        ("2023-01-01", 365, "2024-01-01"),
+    ] {
+        let date: YearMonthDay = input.parse().unwrap();
+        let expected: YearMonthDay = expected.parse().unwrap();
+
+        assert_eq!(
+            date.checked_add_days(days),
+            Some(expected.clone()),
+            "{input}"
+        );
+        assert_eq!(expected.checked_sub_days(days), Some(date), "{input}");
+    }
+}
+
+#[test]
+fn checked_add_days_stays_within_years() {
+    let first: YearMonthDay = "0001-01-01".parse().unwrap();
+    let last: YearMonthDay = "275760-12-31".parse().unwrap();
+
+    assert_eq!(first.checked_sub_days(1), None);
+    assert_eq!(last.checked_add_days(1), None);
+    assert_eq!(first.checked_add_days(i64::MAX), None);
+    assert_eq!(last.checked_sub_days(i64::MIN), None);
+}
+
+#[test]
+fn checked_add_carries_seconds_into_larger_units() {
+    for (input, duration, expected) in [
+        (
+            "2023-12-18T23:59:30",
+            Duration::from_secs(90),
+            "2023-12-19T00:01:00",
+        ),
+        (
+            "2023-12-18T12:34:56",
+            Duration::from_secs(24 * 3600),
+            "2023-12-19T12:34:56",
+        ),
+        (
+            "2023-12-18T12:34:59.75",
+            Duration::from_millis(1500),
+            "2023-12-18T12:35:01.25",
+        ),
+        (
+            "2023-02-28T12:00:00.999",
+            Duration::new(12 * 3600, 1_000_000),
+            "2023-03-01T00:00:01",
+        ),
+    ] {
+        let dt: Datetime = input.parse().unwrap();
+        assert_eq!(
+            dt.checked_add(duration),
+            Some(expected.parse().unwrap()),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn year_month_length_in_days() {
+    let lengths = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    for (month, length) in (1..=12).zip(lengths) {
+        let year_month: YearMonth = format!("2023-{month:02}").parse().unwrap();
+        assert_eq!(year_month.length_in_days(), length, "{month}");
+    }
+
+    for (input, length) in [("2024-02", 29), ("2000-02", 29), ("1900-02", 28)] {
+        let year_month: YearMonth = input.parse().unwrap();
+        assert_eq!(year_month.length_in_days(), length, "{input}");
+    }
+}
+
+#[test]
+fn second_keeps_parsed_fraction_digits() {
+    let second: Second = "56.50".parse().unwrap();
+
+    assert_eq!(second.fractional_digits(), 2);
+    assert_eq!(second, Second::try_from(56.5).unwrap());
+    assert_eq!(Second::try_from(56.5).unwrap().fractional_digits(), 1);
+
+    for input in [
+        "2023-12-18T12:34:56.50",
+        "2023-12-18T12:34:56.000",
+        "2023-12-18T12:34:56.5",
+        "2023-12-18T12:34:56",
+    ] {
+        let dt: Datetime = input.parse().unwrap();
+        assert_eq!(dt.to_string(), input);
+    }
+}
+
+#[test]
+fn format_strips_parsed_trailing_zeros() {
+    let dt: Datetime = "2023-12-18T12:34:56.50".parse().unwrap();
+
+    assert_eq!(
+        dt.format(&FormatOptions::default()),
+        "2023-12-18T12:34:56.5"
+    );
+    assert!(!is_already_normalized("2023-12-18T12:34:56.50").unwrap());
+    assert!(!is_already_normalized("2023-12-18T12:34:00.000").unwrap());
+}
+
+#[test]
+fn error_position_points_at_offending_component() {
+    for (input, component, position) in [
+        ("202a-12-18T12:34:56", Component::Year, 0),
+        ("2023-13-18T12:34:56", Component::Month, 5),
+        ("2023-12-32T12:34:56", Component::Day, 8),
+        ("2023-02-30T12:34:56", Component::Day, 8),
+        ("2023-12-18T25:34:56", Component::Hour, 11),
+        ("2023-12-18 12:60:56", Component::Minute, 14),
+        ("2023-12-18T12:34:60.5", Component::Second, 17),
+        ("12345-12-18T12:34:56.", Component::Second, 18),
+        ("2023-12-18T12:34:56x", Component::Time, 19),
+        ("2023-12-18T12", Component::Minute, 13),
+    ] {
+        let err = input.parse::<Datetime>().unwrap_err();
+        assert_eq!(err.component, component, "{input}");
+        assert_eq!(err.position, Some(position), "{input}");
+    }
+}
+
+#[test]
+fn error_position_in_other_parsers() {
+    let err = "--13-01".parse::<MonthDay>().unwrap_err();
+    assert_eq!(err.position, Some(2));
+    let err = "2023-W54".parse::<YearWeek>().unwrap_err();
+    assert_eq!(err.position, Some(6));
+    let err = "2023-12-18T12:34:56+01:6x"
+        .parse::<GlobalDatetime>()
+        .unwrap_err();
+    assert_eq!(err.position, Some(23));
+
+    let err = Datetime::parse_from_format("18/12/2023 12:61", "%d/%m/%Y %H:%M").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Minute, Some(14)));
+    let err = Datetime::parse_from_format("18/13/2023 12:34", "%d/%m/%Y %H:%M").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Month, Some(3)));
+    let err = Datetime::parse_from_format("2023/12", "%Y-%m").unwrap_err();
+    assert_eq!(
+        (err.component, err.found.as_str(), err.position),
+        (Component::Format, "/12", Some(4))
+    );
+    let err = Datetime::parse_from_format("2023-12-18 12:34!", "%Y-%m-%d %H:%M").unwrap_err();
+    assert_eq!((err.found.as_str(), err.position), ("!", Some(16)));
+    // A mistake in the format string isn't a position in the input.
+    let err = Datetime::parse_from_format("2023-12-18 12:34", "%Y-%m-%d %H:%q").unwrap_err();
+    assert_eq!(
+        (err.kind, err.position),
+        (DateTimeParseErrorKind::UnsupportedSpecifier, None)
+    );
+
+    let err = Datetime::from_fixed19(b"2023-13-18T12:34:56").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Month, Some(5)));
+    let err = Datetime::from_fixed19(b"2023-02-30T12:34:56").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Day, Some(8)));
+    let err = Datetime::from_fixed19(b"2023-12-18T12:34:6x").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Second, Some(17)));
+    let err = Datetime::from_fixed19(b"2023-12-18 12:34:56").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Time, Some(10)));
+
+    // Values that weren't parsed from text have no position.
+    assert_eq!(Second::try_from(60.0).unwrap_err().position, None);
+}
+
+#[test]
+fn parse_local_matches_from_str() {
+    for input in [
+        "2023-12-18T12:34:56.789",
+        "2023-12-18 12:34",
+        "2023-12-18",
+        "2023-1-18T12:34",
+        "2023-12-18T24:00",
+    ] {
+        assert_eq!(
+            Datetime::parse_local(input).ok(),
+            input.parse::<Datetime>().ok(),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn date_str_defaults_to_midnight() {
+    assert!("2023-12-18".parse::<Datetime>().is_err());
+
+    let dt = Datetime::from_date_str_defaulting_midnight("2023-12-18").unwrap();
+    assert_eq!(dt, "2023-12-18T00:00".parse().unwrap());
+    assert_eq!(dt.to_string(), "2023-12-18T00:00:00");
+
+    assert!(Datetime::from_date_str_defaulting_midnight("2023-12-18T12:34").is_err());
+    assert!(Datetime::from_date_str_defaulting_midnight("2023-02-29").is_err());
+}
+
+#[test]
+fn iter_until_crosses_month_and_leap_february() {
+    let ymd = |s: &str| s.parse::<YearMonthDay>().unwrap();
+
+    let days: Vec<_> = ymd("2023-12-30").iter_until(ymd("2024-01-02")).collect();
+    assert_eq!(
+        days,
+        [ymd("2023-12-30"), ymd("2023-12-31"), ymd("2024-01-01")]
+    );
+
+    let days: Vec<_> = ymd("2024-02-27").iter_until(ymd("2024-03-02")).collect();
+    assert_eq!(
+        days,
+        [
+            ymd("2024-02-27"),
+            ymd("2024-02-28"),
+            ymd("2024-02-29"),
+            ymd("2024-03-01")
+        ]
+    );
+    assert_eq!(ymd("2023-02-01").iter_until(ymd("2023-03-01")).count(), 28);
+    assert_eq!(ymd("2024-01-01").iter_until(ymd("2025-01-01")).count(), 366);
+
+    assert_eq!(ymd("2024-03-01").iter_until(ymd("2024-03-01")).count(), 0);
+    assert_eq!(ymd("2024-03-02").iter_until(ymd("2024-03-01")).count(), 0);
+}
+
+#[test]
+fn saturating_from_clamps_components() {
+    assert_eq!(Month::saturating_from(0), Month(1));
+    assert_eq!(Month::saturating_from(200), Month(12));
+    assert_eq!(Month::saturating_from(7), Month(7));
+    assert_eq!(Day::saturating_from(0), Day(1));
+    assert_eq!(Day::saturating_from(40), Day(31));
+    assert_eq!(Hour::saturating_from(24), Hour(23));
+    assert_eq!(Minute::saturating_from(u8::MAX), Minute(59));
+    assert_eq!(Year::saturating_from(-5), Year(1));
+
+    assert!(Month::try_from(0).is_err());
+    assert!(Month::try_from(200).is_err());
+}
+
+#[test]
+fn error_message_names_component() {
+    let err = "2023-13-01T00:00:00".parse::<Datetime>().unwrap_err();
+
+    assert_eq!(
+        err.to_string(),
+        "Failed to parse the month component, found `13`: The value must be at least 1 and at most 12"
+    );
+    assert_eq!(Component::Month.name(), "month");
+    assert_eq!(Component::Offset.to_string(), "offset");
+}
+
+#[test]
+fn comma_decimal_separator() {
+    let lenient = DatetimeParser::new().allow_comma_decimal(true);
+
+    assert_eq!(
+        lenient.parse("2023-12-18T12:34:56,5").unwrap(),
+        "2023-12-18T12:34:56.5".parse().unwrap()
+    );
+    assert_eq!(
+        lenient.parse("2023-12-18T12:34:56.5").unwrap(),
+        "2023-12-18T12:34:56.5".parse().unwrap()
+    );
+    assert!(lenient.parse("2023-12-18T12:34,5").is_err());
+    assert!(lenient.parse("2023-12-18T12:34:56,5,0").is_err());
+
+    let err = DatetimeParser::new()
+        .parse("2023-12-18T12:34:56,5")
+        .unwrap_err();
+    assert_eq!(err.component, Component::Time);
+    assert_eq!(err.position, Some(19));
+}
+
+#[test]
+fn approx_eq_tolerates_small_differences() {
+    let a: Datetime = "2023-12-18T12:34:56.100".parse().unwrap();
+    let b: Datetime = "2023-12-18T12:34:56.101".parse().unwrap();
+
+    assert!(a.approx_eq(&b, 0.001));
+    assert!(b.approx_eq(&a, 0.001));
+    assert!(!a.approx_eq(&b, 0.0));
+    assert!(a.approx_eq(&a.clone(), 0.0));
+
+    // The tolerance also applies across minute boundaries.
+    let c: Datetime = "2023-12-18T12:34:59.999".parse().unwrap();
+    let d: Datetime = "2023-12-18T12:35".parse().unwrap();
+    assert!(c.approx_eq(&d, 0.001));
+    assert!(!c.approx_eq(&d, 0.0005));
+}
+
+#[test]
+fn empty_components_are_missing() {
+    for (input, component, position) in [
+        ("2023--18T12:34:56", Component::Month, 5),
+        ("2023-12-T12:34:56", Component::Day, 8),
+        ("-12-18T12:34:56", Component::Year, 0),
+        ("2023-12-18T12::56", Component::Minute, 14),
+        ("2023-12-18T12:34:", Component::Second, 17),
+        ("--T::", Component::Year, 0),
+    ] {
+        let err = input.parse::<Datetime>().unwrap_err();
+        assert!(
+            matches!(err.kind, DateTimeParseErrorKind::Missing),
+            "{input}: {err}"
+        );
+        assert_eq!(err.component, component, "{input}");
+        assert_eq!(err.position, Some(position), "{input}");
+    }
+
+    let err = ":34:56".parse::<HourMinuteSecond>().unwrap_err();
+    assert_eq!(err.component, Component::Hour);
+    assert!(matches!(err.kind, DateTimeParseErrorKind::Missing));
+}
+
+#[test]
+fn into_parts_round_trips() {
+    let dt: Datetime = "2023-12-18T12:34:56.5".parse().unwrap();
+    let (d, t) = dt.clone().into_parts();
+    assert_eq!(dt, Datetime::from_parts(d, t));
+
+    let other: Datetime = "2000-01-01T08:00".parse().unwrap();
+    let (date, _) = dt.into_parts();
+    let (_, time) = other.into_parts();
+    assert_eq!(
+        Datetime::from_parts(date, time).to_string(),
+        "2023-12-18T08:00:00"
+    );
+}
+
+#[test]
+fn non_ascii_digits_are_rejected() {
+    for (input, component, position) in [
+        ("٢٠٢٣-12-18T12:34:56", Component::Year, 0),
+        ("2023-１２-18T12:34:56", Component::Month, 5),
+        ("2023-12-18T１２:34:56", Component::Hour, 11),
+        ("2023-12-18T12:34:٥٦", Component::Second, 17),
+    ] {
+        let err = input.parse::<Datetime>().unwrap_err();
+        assert!(
+            matches!(
+                err.kind,
+                DateTimeParseErrorKind::InvalidNumber(NumberError::NotAsciiDigits)
+            ),
+            "{input}: {err}"
+        );
+        assert_eq!(err.component, component, "{input}");
+        assert_eq!(err.position, Some(position), "{input}");
+    }
+
+    let err = "+5".parse::<Hour>().unwrap_err();
+    assert_eq!(err.component, Component::Hour);
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::InvalidNumber(NumberError::NotAsciiDigits)
+    ));
+}
+
+#[test]
+fn try_from_str_matches_from_str() {
+    fn parse<'a, T: TryFrom<&'a str>>(s: &'a str) -> Option<T> {
+        T::try_from(s).ok()
+    }
+
+    assert_eq!(
+        parse::<YearMonthDay>("2023-12-18"),
+        "2023-12-18".parse().ok()
+    );
+    assert_eq!(parse::<HourMinuteSecond>("12:34"), "12:34".parse().ok());
+    assert_eq!(
+        parse::<Datetime>("2023-12-18 12:34"),
+        "2023-12-18T12:34".parse().ok()
+    );
+    assert!(HourMinuteSecond::try_from("25:00").is_err());
+}
+
+#[test]
+fn validate_reports_parse_errors() {
+    for input in ["2023-12-18T12:34:56.5", "2023-12-18 12:34"] {
+        assert!(Datetime::validate(input).is_ok(), "{input}");
+    }
+
+    for input in [
+        "2023-12-18",
+        "2023-13-45T25:70:80",
+        "2023-02-29T12:34",
+        "2023-12-18T12:34:56x",
+        "2023--18T12:34",
+    ] {
+        let expected = input.parse::<Datetime>().unwrap_err();
+        let err = Datetime::validate(input).unwrap_err();
+        assert_eq!(err.to_string(), expected.to_string(), "{input}");
+        assert_eq!(err.position, expected.position, "{input}");
+    }
+}
+
+#[test]
+fn parse_optional_treats_blank_input_as_none() {
+    assert_eq!(Datetime::parse_optional("").unwrap(), None);
+    assert_eq!(Datetime::parse_optional("   ").unwrap(), None);
+    assert_eq!(Datetime::parse_optional(" \t\r\n").unwrap(), None);
+    // Only ASCII whitespace counts as blank, as with `DatetimeParser::trim_input`.
+    assert!(Datetime::parse_optional("\u{3000}").is_err());
+    assert_eq!(
+        Datetime::parse_optional("2023-12-18T12:34").unwrap(),
+        Some("2023-12-18T12:34".parse().unwrap())
+    );
+
+    assert!(Datetime::parse_optional(" 2023-12-18T12:34 ").is_err());
+    assert!(Datetime::parse_optional("2023-12-18").is_err());
+}
+
+#[test]
+fn compare_datetime_with_date() {
+    let date: YearMonthDay = "2023-12-18".parse().unwrap();
+    let morning: Datetime = "2023-12-18T00:00".parse().unwrap();
+    let evening: Datetime = "2023-12-18T23:59:59.999".parse().unwrap();
+    let next_day: Datetime = "2023-12-19T00:00".parse().unwrap();
+
+    assert!(morning.is_on_date(&date));
+    assert!(evening.is_on_date(&date));
+    assert!(!next_day.is_on_date(&date));
+
+    assert_eq!(evening.date_cmp(&date), Ordering::Equal);
+    assert_eq!(next_day.date_cmp(&date), Ordering::Greater);
+    assert_eq!(
+        morning.date_cmp(&"2024-01-01".parse().unwrap()),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn parse_collecting_errors_reports_every_component() {
+    let errors = Datetime::parse_collecting_errors("2023-13-45T25:70:80").unwrap_err();
+    let found: Vec<_> = errors
+        .iter()
+        .map(|err| (err.component, err.position))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (Component::Month, Some(5)),
+            (Component::Day, Some(8)),
+            (Component::Hour, Some(11)),
+            (Component::Minute, Some(14)),
+            (Component::Second, Some(17)),
+        ]
+    );
+
+    assert_eq!(
+        Datetime::parse_collecting_errors("2023-12-18T12:34").unwrap(),
+        "2023-12-18T12:34".parse().unwrap()
+    );
+
+    // Invalid dates are reported once their components are valid on their own.
+    let errors = Datetime::parse_collecting_errors("2023-02-30T24:00").unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].component, Component::Day);
+
+    // Malformed input gets the same single error as `from_str`.
+    for input in [
+        "2023-12-18",
+        "2023-12-18T12:34:56:00",
+        "2023-12-18T12:34:56x",
+    ] {
+        let errors = Datetime::parse_collecting_errors(input).unwrap_err();
+        let expected = input.parse::<Datetime>().unwrap_err();
+        assert_eq!(errors.len(), 1, "{input}");
+        assert_eq!(errors[0].to_string(), expected.to_string(), "{input}");
+    }
+}
+
+#[test]
+fn year_bounds() {
+    assert_eq!(Year::try_from(Year::MAX).unwrap(), Year(275_760));
+    assert!(Year::try_from(Year::MAX + 1).is_err());
+    assert!(Year::try_from(Year::MIN - 1).is_err());
+
+    assert_eq!(
+        Year::try_from_i64(i64::from(Year::MAX)).unwrap(),
+        Year(Year::MAX)
+    );
+    let err = Year::try_from_i64(i64::from(Year::MAX) + 1).unwrap_err();
+    assert_eq!(err.found, "275761");
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::OutOfRange {
+            min: Year::MIN,
+            max: Year::MAX
+        }
+    ));
+    let err = Year::try_from_i64(i64::MIN).unwrap_err();
+    assert_eq!(err.found, i64::MIN.to_string());
+
+    assert!("275760-12-31T23:59".parse::<Datetime>().is_ok());
+    assert!("275761-01-01T00:00".parse::<Datetime>().is_err());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn now_utc_is_recent() {
+    let now = Datetime::now_utc();
+
+    assert!(now.date().year() > 2020);
+    assert!(now <= Datetime::now_utc());
+}
+
+#[test]
+fn iso_week_near_year_boundaries() {
+    for (date, expected) in [
+        ("2021-01-01", (2020, 53)),
+        ("2023-01-01", (2022, 52)),
+        ("2023-01-02", (2023, 1)),
+        ("2023-12-18", (2023, 51)),
+        ("2024-12-30", (2025, 1)),
+        ("2020-12-31", (2020, 53)),
+        ("0001-01-01", (1, 1)),
+    ] {
+        let date: YearMonthDay = date.parse().unwrap();
+        assert_eq!(date.iso_week(), expected, "{date}");
+    }
+
+    // Every week of 2023 has seven days.
+    let start: YearMonthDay = "2023-01-02".parse().unwrap();
+    let weeks: Vec<_> = start
+        .iter_until("2024-01-01".parse().unwrap())
+        .map(|date| date.iso_week())
+        .collect();
+    assert!(weeks
+        .chunks(7)
+        .enumerate()
+        .all(|(index, chunk)| chunk.iter().all(|&week| week == (2023, index as u8 + 1))));
+}
+
+#[test]
+fn errors_can_be_cloned_and_compared() {
+    let err = "2023-13-01T00:00".parse::<Datetime>().unwrap_err();
+    let copy = err.clone();
+
+    assert_eq!(copy, err);
+    assert_eq!(copy.to_string(), err.to_string());
+    assert_ne!(err, "2023-12-32T00:00".parse::<Datetime>().unwrap_err());
+    assert_eq!(
+        "2023-1a-18T00:00".parse::<Datetime>().unwrap_err().kind,
+        DateTimeParseErrorKind::InvalidNumber(NumberError::NotAsciiDigits)
+    );
+}
+
+#[test]
+fn basic_format() {
+    let basic = DatetimeParser::new().allow_basic_format(true);
+
+    assert_eq!(
+        basic.parse("20231218T123456").unwrap(),
+        "2023-12-18T12:34:56".parse().unwrap()
+    );
+    assert_eq!(
+        basic.parse("20231218T1234").unwrap(),
+        "2023-12-18T12:34".parse().unwrap()
+    );
+    assert_eq!(
+        basic.parse("2023-12-18T12:34").unwrap(),
+        "2023-12-18T12:34".parse().unwrap()
+    );
+    assert!(DatetimeParser::new().parse("20231218T123456").is_err());
+    assert!("20231218T123456".parse::<Datetime>().is_err());
+
+    let err = basic.parse("2023121T123456").unwrap_err();
+    assert_eq!(err.component, Component::Date);
+    let err = basic.parse("20231218T12345").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Time, Some(9)));
+    let err = basic.parse("20231318T123456").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Month, Some(4)));
+    let err = basic.parse("20231218T126056").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Minute, Some(11)));
+}
+
+#[test]
+fn add_minutes_carries_days() {
+    let time = |s: &str| s.parse::<HourMinuteSecond>().unwrap();
+
+    assert_eq!(time("23:30:00").add_minutes(90), (time("01:00:00"), 1));
+    assert_eq!(time("12:34:56.5").add_minutes(0), (time("12:34:56.5"), 0));
+    assert_eq!(time("00:15").add_minutes(-30), (time("23:45"), -1));
+    assert_eq!(time("00:00").add_minutes(-1440), (time("00:00"), -1));
+    assert_eq!(time("10:00").add_minutes(3 * 1440 + 61), (time("11:01"), 3));
+    // The carry can't overflow.
+    assert_eq!(time("00:00").add_minutes(i64::MAX).1, i64::MAX / 1440);
+    assert_eq!(
+        time("00:00").add_minutes(i64::MIN).1,
+        i64::MIN.div_euclid(1440)
+    );
+}
+
+#[test]
+fn const_component_constructors() {
+    const DEC: Option<Month> = Month::new(12);
+    const NOON: Option<Hour> = Hour::new(12);
+
+    assert_eq!(DEC, Some(Month(12)));
+    assert_eq!(NOON, Some(Hour(12)));
+    assert_eq!(Month::new(0), None);
+    assert_eq!(Month::new(13), None);
+    assert_eq!(Day::new(31), Some(Day(31)));
+    assert_eq!(Day::new(32), None);
+    assert_eq!(Hour::new(0), Some(Hour(0)));
+    assert_eq!(Hour::new(24), None);
+    assert_eq!(Minute::new(59), Some(Minute(59)));
+    assert_eq!(Minute::new(60), None);
+    assert_eq!(Year::new(Year::MAX + 1), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_structured_round_trip() {
+    let dt = StructuredDatetime("2023-12-18T12:34:56.5".parse().unwrap());
+
+    let json = serde_json::to_string(&dt).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"year":2023,"month":12,"day":18,"hour":12,"minute":34,"second":56.5}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<StructuredDatetime>(&json).unwrap(),
+        dt
+    );
+    assert_eq!(
+        serde_json::from_str::<StructuredDatetime>(
+            r#"{"year":2023,"month":12,"day":18,"hour":12,"minute":34}"#
+        )
+        .unwrap()
+        .0,
+        "2023-12-18T12:34".parse().unwrap()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_structured_rejects_invalid_components() {
+    for json in [
+        r#"{"month":13}"#,
+        r#"{"year":2023,"month":13,"day":1,"hour":0,"minute":0}"#,
+        r#"{"year":2023,"month":2,"day":29,"hour":0,"minute":0}"#,
+        r#"{"year":2023,"month":1,"day":1,"hour":0,"minute":0,"second":60}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<StructuredDatetime>(json).is_err(),
+            "{json}"
+        );
+    }
+
+    let err = serde_json::from_str::<StructuredDatetime>(
+        r#"{"year":2023,"month":13,"day":1,"hour":0,"minute":0}"#,
+    )
+    .unwrap_err();
+    assert!(err.to_string().contains("month"), "{err}");
+}
+
+#[test]
+fn second_requires_digits_after_dot() {
+    let err = "56.".parse::<Second>().unwrap_err();
+    assert_eq!(
+        err.kind,
+        DateTimeParseErrorKind::InvalidNumber(NumberError::InvalidFraction)
+    );
+    assert_eq!(err.found, "56.");
+
+    assert_eq!("56".parse::<Second>().unwrap(), Second::new(56_000));
+    assert_eq!("56.0".parse::<Second>().unwrap(), Second::new(56_000));
+    assert!("2023-12-18T12:34:56.".parse::<Datetime>().is_err());
+}
+
+#[test]
+fn functional_updates() {
+    let dt: Datetime = "2024-01-31 12:34:56".parse().unwrap();
+
+    assert_eq!(
+        dt.with_time("08:00".parse().unwrap()).to_string(),
+        "2024-01-31T08:00:00"
+    );
+    assert_eq!(
+        dt.with_date("2000-06-15".parse().unwrap()).to_string(),
+        "2000-06-15T12:34:56"
+    );
+    assert_eq!(dt.to_string(), "2024-01-31T12:34:56");
+
+    let err = dt.with_month(2).unwrap_err();
+    assert_eq!(err.component, Component::Day);
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::OutOfRange { min: 1, max: 29 }
+    ));
+    assert_eq!(dt.with_month(13).unwrap_err().component, Component::Month);
+
+    let leap_day: Datetime = "2024-02-29T00:00".parse().unwrap();
+    assert_eq!(
+        leap_day.with_year(2028).unwrap(),
+        "2028-02-29T00:00".parse().unwrap()
+    );
+    assert_eq!(
+        leap_day.with_year(2023).unwrap_err().component,
+        Component::Day
+    );
+    assert_eq!(
+        leap_day.with_year(0).unwrap_err().component,
+        Component::Year
+    );
+}
+
+#[test]
+fn truncate_and_round() {
+    let dt = |s: &str| s.parse::<Datetime>().unwrap();
+    let original = dt("2023-12-18T12:34:56.789");
+
+    assert_eq!(original.truncate_to_minute(), dt("2023-12-18T12:34"));
+    assert_eq!(original.truncate_to_hour(), dt("2023-12-18T12:00"));
+    assert_eq!(original.truncate_to_day(), dt("2023-12-18T00:00"));
+    assert_eq!(
+        original.truncate_to_minute().to_string(),
+        "2023-12-18T12:34:00"
+    );
+    assert_eq!(original, dt("2023-12-18T12:34:56.789"));
+
+    assert_eq!(
+        dt("2023-12-18T12:34:56").round_to_nearest_minute(),
+        Some(dt("2023-12-18T12:35"))
+    );
+    assert_eq!(
+        dt("2023-12-18T12:34:29.999").round_to_nearest_minute(),
+        Some(dt("2023-12-18T12:34"))
+    );
+    assert_eq!(
+        dt("2023-12-18T12:34:30").round_to_nearest_minute(),
+        Some(dt("2023-12-18T12:35"))
+    );
+    assert_eq!(
+        dt("2023-12-18T12:59:45").round_to_nearest_minute(),
+        Some(dt("2023-12-18T13:00"))
+    );
+    assert_eq!(
+        dt("2023-12-31T23:59:30").round_to_nearest_minute(),
+        Some(dt("2024-01-01T00:00"))
+    );
+    assert_eq!(Datetime::MAX.round_to_nearest_minute(), None);
+}
+
+#[test]
+fn error_predicates() {
+    let out_of_range = "2023-13-01T00:00".parse::<Datetime>().unwrap_err();
+    assert!(out_of_range.is_out_of_range());
+    assert!(!out_of_range.is_invalid_number());
+    assert!(!out_of_range.is_missing_component());
+    assert_eq!(out_of_range.component(), Component::Month);
+
+    let invalid_number = "2023-1x-01T00:00".parse::<Datetime>().unwrap_err();
+    assert!(invalid_number.is_invalid_number());
+    assert!(!invalid_number.is_out_of_range());
+    assert_eq!(invalid_number.component(), Component::Month);
+
+    let missing = "2023-12-01".parse::<Datetime>().unwrap_err();
+    assert!(missing.is_missing_component());
+    assert!(!missing.is_out_of_range());
+    assert_eq!(missing.component(), Component::Time);
+
+    let unexpected = "2023-12-01T00:00x".parse::<Datetime>().unwrap_err();
+    assert!(!unexpected.is_out_of_range());
+    assert!(!unexpected.is_invalid_number());
+    assert!(!unexpected.is_missing_component());
+}
+
+#[test]
+fn expanded_year_sign() {
+    let expanded = DatetimeParser::new().allow_expanded_year(true);
+
+    let dt = expanded.parse("+2023-12-18T00:00:00").unwrap();
+    assert_eq!(dt, "2023-12-18T00:00:00".parse().unwrap());
+    assert_eq!(dt.to_string(), "+2023-12-18T00:00:00");
+    // The zero padding of the year is kept along with the sign.
+    let dt = expanded.parse("+012023-12-18T00:00").unwrap();
+    assert_eq!(dt.date.year, Year::new(12023).unwrap());
+    assert_eq!(dt.to_string(), "+012023-12-18T00:00:00");
+    assert_eq!(
+        expanded.parse(&dt.to_string()).unwrap().to_string(),
+        dt.to_string()
+    );
+    assert_eq!(
+        expanded.parse("+12023-12-18T00:00").unwrap().to_string(),
+        "+12023-12-18T00:00:00"
+    );
+    assert_eq!(
+        expanded.parse("2023-12-18T00:00").unwrap().to_string(),
+        "2023-12-18T00:00:00"
+    );
+
+    assert!("+2023-12-18T00:00:00".parse::<Datetime>().is_err());
+    assert!(expanded.parse("-2023-12-18T00:00:00").is_err());
+    let err = expanded.parse("+2023-13-18T00:00:00").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Month, Some(6)));
+    let err = expanded.parse("+2023-12-18T25:00:00").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Hour, Some(12)));
+}
+
+#[test]
+fn string_from_datetime() {
+    let dt: Datetime = "2023-12-18 12:34:56.50".parse().unwrap();
+
+    assert_eq!(String::from(&dt), dt.to_string());
+    assert_eq!(String::from(dt.clone()), dt.to_string());
+    let s: String = dt.into();
+    assert_eq!(s, "2023-12-18T12:34:56.50");
+}
+
+#[test]
+fn compare_same_precision_ignores_unwritten_seconds() {
+    let dt = |s: &str| s.parse::<Datetime>().unwrap();
+
+    assert!(!dt("2023-12-18T12:34").time.seconds_specified());
+    assert!(dt("2023-12-18T12:34:00").time.seconds_specified());
+
+    // Strict comparison treats missing seconds as zero.
+    assert_eq!(dt("2023-12-18T12:34"), dt("2023-12-18T12:34:00"));
+    assert_ne!(dt("2023-12-18T12:34"), dt("2023-12-18T12:34:56"));
+
+    assert_eq!(
+        dt("2023-12-18T12:34").compare_same_precision(&dt("2023-12-18T12:34:00")),
+        Ordering::Equal
+    );
+    assert_eq!(
+        dt("2023-12-18T12:34:56").compare_same_precision(&dt("2023-12-18T12:34")),
+        Ordering::Equal
+    );
+    assert_eq!(
+        dt("2023-12-18T12:34:56").compare_same_precision(&dt("2023-12-18T12:34:00")),
+        Ordering::Greater
+    );
+    assert_eq!(
+        dt("2023-12-18T12:34:56").compare_same_precision(&dt("2023-12-18T12:35")),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn trim_input() {
+    let trimming = DatetimeParser::new().trim_input(true);
+
+    assert_eq!(
+        trimming.parse("  2023-12-18T12:34:56  ").unwrap(),
+        "2023-12-18T12:34:56".parse().unwrap()
+    );
+    assert_eq!(
+        trimming.parse("\t2023-12-18 12:34\r\n").unwrap(),
+        "2023-12-18T12:34".parse().unwrap()
+    );
+    assert!(DatetimeParser::new()
+        .parse("  2023-12-18T12:34:56  ")
+        .is_err());
+    assert!("  2023-12-18T12:34:56  ".parse::<Datetime>().is_err());
+
+    assert!(trimming.parse("2023-12-18T12: 34:56").is_err());
+    assert!(trimming.parse("2023-12-18  12:34:56").is_err());
+    let err = trimming.parse("  2023-13-18T12:34:56").unwrap_err();
+    assert_eq!((err.component, err.position), (Component::Month, Some(7)));
+}
+
+#[test]
+fn quarter_boundaries() {
+    for (date, quarter) in [
+        ("2023-01-01", 1),
+        ("2023-03-31", 1),
+        ("2023-04-01", 2),
+        ("2023-06-30", 2),
+        ("2023-07-01", 3),
+        ("2023-09-30", 3),
+        ("2023-10-01", 4),
+        ("2023-12-31", 4),
+    ] {
+        let date: YearMonthDay = date.parse().unwrap();
+        assert_eq!(date.quarter(), quarter, "{date}");
+    }
+}
+
+#[test]
+fn default_values() {
+    assert_eq!(HourMinuteSecond::default(), "00:00:00".parse().unwrap());
+    assert_eq!(HourMinuteSecond::default().to_string(), "00:00:00");
+
+    assert_eq!(Datetime::default(), Datetime::UNIX_EPOCH);
+    assert_eq!(Datetime::default().to_string(), "1970-01-01T00:00:00");
+    assert_eq!(Datetime::default().to_unix_timestamp(), 0);
+}
+
+// Counts the allocations of each thread separately, so that tests running in parallel don't
+// interfere with each other.
+struct CountingAllocator;
+
+std::thread_local! {
+    static ALLOCATIONS: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}
+
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        std::alloc::System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        std::alloc::System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations_during(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(|count| count.get());
+    f();
+    ALLOCATIONS.with(|count| count.get()) - before
+}
+
+#[test]
+fn parse_borrowed_does_not_allocate_on_failure() {
+    let lenient = DatetimeParser::new()
+        .allow_end_of_day(true)
+        .allow_comma_decimal(true)
+        .allow_basic_format(true)
+        .allow_expanded_year(true)
+        .trim_input(true);
+
+    for (parser, input) in [
+        (DatetimeParser::new(), ""),
+        (DatetimeParser::new(), "2023-12-18"),
+        (DatetimeParser::new(), "2023-13-18T12:34"),
+        (DatetimeParser::new(), "2023-02-30T12:34"),
+        (DatetimeParser::new(), "2023-12-18-01T12:34"),
+        (DatetimeParser::new(), "2023-12-18T12:34T56"),
+        (DatetimeParser::new(), "2023-12-18T12:34:60"),
+        (DatetimeParser::new(), "2023-12-18T12:34:56.1234"),
+        (DatetimeParser::new(), "2023-12-18T24:00"),
+        (DatetimeParser::new(), "٢٠٢٣-12-18T12:34"),
+        (lenient, " 2023-12-18T24:01 "),
+        (lenient, "275760-12-31T24:00"),
+        (lenient, "2023-12-18T12:34:60,5"),
+        (lenient, "20231318T1234"),
+    ] {
+        let mut result = None;
+        let allocations = allocations_during(|| result = Some(parser.parse_borrowed(input)));
+        let err = result.unwrap().unwrap_err();
+        assert_eq!(allocations, 0, "{input}");
+        assert_eq!(
+            err.into_owned(),
+            parser.parse(input).unwrap_err(),
+            "{input}"
+        );
+    }
+}
+
+#[test]
+fn borrowed_error_refers_to_the_input() {
+    let input = String::from("2023-12-18T12:61");
+    let err = DatetimeParser::new().parse_borrowed(&input).unwrap_err();
+    assert_eq!(err.component, Component::Minute);
+    assert_eq!(err.found, "61");
+    assert_eq!(err.position, Some(14));
+    assert_eq!(
+        err.to_string(),
+        "Failed to parse the minute component, found `61`: The value must be at least 0 and at most 59"
+    );
+
+    let owned: DateTimeParseError = err.into();
+    drop(input);
+    assert_eq!(owned.found, "61");
+}
+
+#[test]
+fn month_names() {
+    assert_eq!(Month(1).name(), "January");
+    assert_eq!(Month(12).name(), "December");
+    assert_eq!(Month(9).abbreviation(), "Sep");
+
+    assert_eq!(Month::from_name("february"), Some(Month(2)));
+    assert_eq!(Month::from_name("MAY"), Some(Month(5)));
+    for month in 1..=12 {
+        assert_eq!(Month::from_name(Month(month).name()), Some(Month(month)));
+    }
+
+    for name in ["", "Feb", "02", "Februar", " February"] {
+        assert_eq!(Month::from_name(name), None, "{name}");
+    }
+}
+
+#[test]
+fn setters_validate_before_mutating() {
+    let mut dt: Datetime = "2023-02-10T12:34:56".parse().unwrap();
+    let original = dt.clone();
+
+    let err = dt.set_day(30).unwrap_err();
+    assert_eq!(err.component, Component::Day);
+    assert!(matches!(
+        err.kind,
+        DateTimeParseErrorKind::OutOfRange { min: 1, max: 28 }
+    ));
+    assert!(dt.set_day(29).is_err());
+    assert!(dt.set_month(13).is_err());
+    assert!(dt.set_year(0).is_err());
+    assert!(dt.set_hour(24).is_err());
+    assert!(dt.set_minute(60).is_err());
+    assert!(dt.set_second(60.0).is_err());
+    assert!(dt.set_second(f32::NAN).is_err());
+    assert_eq!(dt, original);
+    assert_eq!(dt.to_string(), "2023-02-10T12:34:56");
+
+    dt.set_year(2024).unwrap();
+    dt.set_day(29).unwrap();
+    // February 29 only exists in leap years.
+    assert!(dt.set_year(2023).is_err());
+    // March 30 exists, but February 30 doesn't.
+    dt.set_month(3).unwrap();
+    dt.set_day(30).unwrap();
+    assert!(dt.set_month(2).is_err());
+    dt.set_hour(23).unwrap();
+    dt.set_minute(5).unwrap();
+    dt.set_second(7.25).unwrap();
+    assert_eq!(dt.to_string(), "2024-03-30T23:05:07.25");
+}
+
+#[test]
+fn coarse_containment() {
+    let year = Year(2023);
+    assert!(year.contains(&"2023-01-01T00:00".parse().unwrap()));
+    assert!(year.contains(&"2023-12-31T23:59:59.999".parse().unwrap()));
+    assert!(!year.contains(&"2024-01-01T00:00".parse().unwrap()));
+    assert!(!year.contains(&"2022-12-31T23:59:59".parse().unwrap()));
+
+    let month: YearMonth = "2024-02".parse().unwrap();
+    assert!(month.contains(&"2024-02-01".parse().unwrap()));
+    assert!(month.contains(&"2024-02-29".parse().unwrap()));
+    assert!(!month.contains(&"2024-03-01".parse().unwrap()));
+    assert!(!month.contains(&"2023-02-01".parse().unwrap()));
+}
+
+#[test]
+fn global_datetime_to_rfc3339() {
+    for (input, expected) in [
+        ("2023-12-18T12:34:56Z", "2023-12-18T12:34:56Z"),
+        ("2023-12-18T12:34:56+00:00", "2023-12-18T12:34:56Z"),
+        ("2023-12-18T12:34:56+05:30", "2023-12-18T12:34:56+05:30"),
+        ("2023-12-18T12:34:56.5-08:00", "2023-12-18T12:34:56.5-08:00"),
+        ("2023-12-18 12:34-03:30", "2023-12-18T12:34:00-03:30"),
+    ] {
+        let dt: GlobalDatetime = input.parse().unwrap();
+        assert_eq!(dt.to_rfc3339(), expected, "{input}");
+    }
+}
+
+#[test]
+fn global_datetime_to_utc() {
+    for (input, expected) in [
+        ("2023-12-18T12:34:56Z", "2023-12-18T12:34:56"),
+        ("2023-12-18T12:34:56.5+00:00", "2023-12-18T12:34:56.5"),
+        // A positive offset is ahead of UTC, so shortly after midnight it's still yesterday.
+        ("2023-12-18T02:15+05:30", "2023-12-17T20:45"),
+        ("2024-03-01T00:00+05:30", "2024-02-29T18:30"),
+        ("2024-01-01T05:00:00+05:30", "2023-12-31T23:30:00"),
+        // A negative offset is behind UTC, so late in the evening it's already tomorrow.
+        ("2023-12-18T22:30-03:00", "2023-12-19T01:30"),
+        ("2023-12-31T23:59:59.999-00:01", "2024-01-01T00:00:59.999"),
+        ("2023-02-28T20:00-08:00", "2023-03-01T04:00"),
+    ] {
+        let dt: GlobalDatetime = input.parse().unwrap();
+        assert_eq!(
+            dt.to_utc().unwrap(),
+            expected.parse::<Datetime>().unwrap(),
+            "{input}"
+        );
+    }
+
+    let dt: GlobalDatetime = "0001-01-01T00:00+01:00".parse().unwrap();
+    assert_eq!(dt.to_utc(), None);
+}
+
+#[test]
+fn days_in_every_month() {
+    let common = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    let leap = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+    for (year, lengths) in [(2023, common), (2024, leap), (1900, common), (2000, leap)] {
+        let mut total = 0;
+        for (month, length) in (1..=12).zip(lengths) {
+            assert_eq!(
+                days_in_month(Year(year), Month(month)),
+                length,
+                "{year}-{month:02}"
+            );
+            let year_month: YearMonth = format!("{year:04}-{month:02}").parse().unwrap();
+            assert_eq!(year_month.length_in_days(), length);
+
+            let last_day = YearMonthDay::from_components(Year(year), Month(month), Day(length));
+            total += u16::from(length);
+            assert_eq!(last_day.unwrap().ordinal(), total);
+            assert!(
+                YearMonthDay::from_components(Year(year), Month(month), Day(length + 1)).is_err()
+            );
+        }
+        assert_eq!(total, if Year(year).is_leap() { 366 } else { 365 });
+    }
+}
+
+#[test]
+fn single_digit_time_fields_require_lenient_mode() {
+    let lenient = DatetimeParser::new().allow_single_digit_fields(true);
+    assert_eq!(
+        lenient.parse("2023-12-18T9:5:3").unwrap(),
+        "2023-12-18T09:05:03".parse().unwrap()
+    );
+    assert_eq!(
+        lenient.parse("2023-12-18T9:05:3.5").unwrap(),
+        "2023-12-18T09:05:03.5".parse().unwrap()
+    );
+    assert!(lenient.parse("2023-12-18T123:05").is_err());
+
+    for (input, component, position) in [
+        ("2023-12-18T9:5:3", Component::Hour, 11),
+        ("2023-12-18T09:5:3", Component::Minute, 14),
+        ("2023-12-18T09:05:3", Component::Second, 17),
+        ("2023-12-18T09:05:3.5", Component::Second, 17),
+        ("2023-12-18T009:05", Component::Hour, 11),
+    ] {
+        let err = DatetimeParser::new().parse(input).unwrap_err();
+        assert_eq!(
+            (err.component, err.position),
+            (component, Some(position)),
+            "{input}"
+        );
+        assert!(
+            matches!(
+                err.kind,
+                DateTimeParseErrorKind::InvalidNumber(NumberError::NotTwoDigits)
+            ),
+            "{input}"
+        );
+    }
+
+    let errors = Datetime::parse_collecting_errors("2023-13-18T9:05:3").unwrap_err();
+    let components: Vec<_> = errors.iter().map(|err| err.component).collect();
+    assert_eq!(
+        components,
+        [Component::Month, Component::Hour, Component::Second]
+    );
+}
+
+#[test]
+fn full_years_until_counts_completed_years() {
+    let years = |from: &str, to: &str| {
+        from.parse::<YearMonthDay>()
+            .unwrap()
+            .full_years_until(&to.parse().unwrap())
+    };
+
+    assert_eq!(years("1990-06-15", "1990-06-15"), 0);
+    assert_eq!(years("1990-06-15", "2023-06-14"), 32);
+    assert_eq!(years("1990-06-15", "2023-06-15"), 33);
+    assert_eq!(years("1990-06-15", "2023-12-31"), 33);
+    assert_eq!(years("1990-12-31", "1991-01-01"), 0);
+
+    // A leap day birthday is reached on February 28 in common years.
+    assert_eq!(years("2000-02-29", "2001-02-27"), 0);
+    assert_eq!(years("2000-02-29", "2001-02-28"), 1);
+    assert_eq!(years("2000-02-29", "2003-03-01"), 3);
+    assert_eq!(years("2000-02-29", "2004-02-28"), 3);
+    assert_eq!(years("2000-02-29", "2004-02-29"), 4);
+
+    // Counting backwards gives the same number of years, negated.
+    assert_eq!(years("2023-06-15", "1990-06-15"), -33);
+    assert_eq!(years("2023-06-14", "1990-06-15"), -32);
+}
+
+#[test]
+fn datetime_value_picks_the_format_by_shape() {
+    for (input, expected) in [
+        (
+            "2023-12-18",
+            DatetimeValue::Date("2023-12-18".parse().unwrap()),
+        ),
+        ("2023-12", DatetimeValue::Month("2023-12".parse().unwrap())),
+        ("2023-W51", DatetimeValue::Week("2023-W51".parse().unwrap())),
+        (
+            "--12-18",
+            DatetimeValue::Yearless("--12-18".parse().unwrap()),
+        ),
+        ("12-18", DatetimeValue::Yearless("--12-18".parse().unwrap())),
+        ("12:34", DatetimeValue::Time("12:34".parse().unwrap())),
+        (
+            "12:34:56.5",
+            DatetimeValue::Time("12:34:56.5".parse().unwrap()),
+        ),
+        (
+            "2023-12-18T12:34",
+            DatetimeValue::Local("2023-12-18T12:34".parse().unwrap()),
+        ),
+        (
+            "2023-12-18 12:34:56",
+            DatetimeValue::Local("2023-12-18T12:34:56".parse().unwrap()),
+        ),
+        (
+            "2023-12-18T12:34Z",
+            DatetimeValue::Global("2023-12-18T12:34Z".parse().unwrap()),
+        ),
+        (
+            "2023-12-18T12:34:56-05:30",
+            DatetimeValue::Global("2023-12-18T12:34:56-05:30".parse().unwrap()),
+        ),
+    ] {
+        let value = DatetimeValue::parse(input).unwrap();
+        assert_eq!(value, expected, "{input}");
+        assert_eq!(input.parse::<DatetimeValue>().unwrap(), expected, "{input}");
+    }
+
+    assert_eq!(
+        DatetimeValue::parse("2023-12-18T12:34")
+            .unwrap()
+            .to_string(),
+        "2023-12-18T12:34:00"
+    );
+}
+
+#[test]
+fn datetime_value_rejects_junk() {
+    for (input, component) in [
+        ("", Component::Month),
+        ("hello", Component::Month),
+        ("2023", Component::Month),
+        ("2023-12-18-01", Component::Date),
+        ("2023-13", Component::Month),
+        ("13-45", Component::Month),
+        ("2023-W54", Component::Week),
+        ("25:00", Component::Hour),
+        ("2023-12-18T", Component::Minute),
+        ("2023-12-18T12:34+25:00", Component::Offset),
+        ("T-W:", Component::Month),
+    ] {
+        let err = DatetimeValue::parse(input).unwrap_err();
+        assert_eq!(err.component, component, "{input}");
+    }
+}
+
+#[test]
+fn error_sources_lead_to_the_parse_int_error() {
+    use std::error::Error as _;
+
+    // Too large for an `i32`, so the digits fail to parse as a number.
+    let err = "99999999999-12-18T12:34".parse::<Datetime>().unwrap_err();
+    assert!(err.is_invalid_number());
+    let message = err.to_string();
+
+    let mut sources = Vec::new();
+    let mut source = err.source();
+    while let Some(current) = source {
+        sources.push(current);
+        source = current.source();
+    }
+    assert_eq!(sources.len(), 3);
+    assert!(sources[0]
+        .downcast_ref::<DateTimeParseErrorKind>()
+        .is_some());
+    assert!(sources[1].downcast_ref::<NumberError>().is_some());
+    let int_error = sources[2]
+        .downcast_ref::<std::num::ParseIntError>()
+        .unwrap();
+    assert_eq!(*int_error.kind(), std::num::IntErrorKind::PosOverflow);
+    // Each error in the chain only describes itself, so that reporters that print the whole
+    // chain don't repeat the `ParseIntError`'s message.
+    assert!(!message.contains(&int_error.to_string()));
+    assert!(!sources[0].to_string().contains(&int_error.to_string()));
+    assert!(!sources[1].to_string().contains(&int_error.to_string()));
+
+    // Errors that don't wrap another one end the chain at the kind.
+    let err = "2023-13-18T12:34".parse::<Datetime>().unwrap_err();
+    assert!(err.source().unwrap().source().is_none());
+}
+
+#[test]
+fn format_with_precision_rounds_half_to_even() {
+    for (input, digits, expected) in [
+        ("2023-12-18T12:34:56.5", 3, "2023-12-18T12:34:56.500"),
+        ("2023-12-18T12:34:56.5", 5, "2023-12-18T12:34:56.50000"),
+        ("2023-12-18T12:34:56.5", 1, "2023-12-18T12:34:56.5"),
+        ("2023-12-18T12:34:56.5", 0, "2023-12-18T12:34:56"),
+        ("2023-12-18T12:34:57.5", 0, "2023-12-18T12:34:58"),
+        ("2023-12-18T12:34:56.501", 0, "2023-12-18T12:34:57"),
+        ("2023-12-18T12:34:56.25", 1, "2023-12-18T12:34:56.2"),
+        ("2023-12-18T12:34:56.35", 1, "2023-12-18T12:34:56.4"),
+        ("2023-12-18T12:34:56.125", 2, "2023-12-18T12:34:56.12"),
+        ("2023-12-18T12:34:56.126", 2, "2023-12-18T12:34:56.13"),
+        ("2023-12-18T12:34", 1, "2023-12-18T12:34:00.0"),
+        ("2023-12-18T12:34", 0, "2023-12-18T12:34:00"),
+        // Rounding up carries into the minute, and from there into the date.
+        ("2023-12-18T12:34:59.96", 1, "2023-12-18T12:35:00.0"),
+        ("2023-12-31T23:59:59.5", 0, "2024-01-01T00:00:00"),
+    ] {
+        let dt: Datetime = input.parse().unwrap();
+        assert_eq!(
+            dt.format_with_precision(digits),
+            expected,
+            "{input} with {digits} digits"
+        );
+    }
+
+    // The latest datetime has no next minute to carry into.
+    let max = Datetime::MAX.with_time("23:59:59.999".parse().unwrap());
+    assert_eq!(max.format_with_precision(0), "275760-12-31T23:59:59");
+}
+
+#[test]
+fn offset_colon_is_optional() {
+    for (input, minutes) in [
+        ("2023-12-18T12:34:56+0530", 330),
+        ("2023-12-18T12:34:56-0800", -480),
+        ("2023-12-18T12:34:56+0000", 0),
+    ] {
+        let global: GlobalDatetime = input.parse().unwrap();
+        assert_eq!(global.offset.minutes(), minutes, "{input}");
+    }
+    assert_eq!(
+        "+0530".parse::<TimezoneOffset>().unwrap(),
+        "+05:30".parse().unwrap()
+    );
+    // Displayed with the colon.
+    assert_eq!(
+        "2023-12-18T12:34:56+0530"
+            .parse::<GlobalDatetime>()
+            .unwrap()
+            .to_string(),
+        "2023-12-18T12:34:56+05:30"
+    );
+
+    let err = "+0560".parse::<TimezoneOffset>().unwrap_err();
+    assert!(err.is_out_of_range());
+    let err = "+05".parse::<TimezoneOffset>().unwrap_err();
+    assert!(err.is_missing_component());
+    let err = "+05x0".parse::<TimezoneOffset>().unwrap_err();
+    assert_eq!(err.position, Some(3));
 }
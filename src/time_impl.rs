@@ -0,0 +1,60 @@
+//! Conversions between [`Datetime`] and [`time::PrimitiveDateTime`].
+
+use crate::{
+    Component, DateTimeParseError, DateTimeParseErrorKind, Datetime, Day, Hour, HourMinuteSecond,
+    Minute, Month, Second, Separator, Year, YearMonthDay,
+};
+use alloc::string::ToString;
+use core::convert::TryFrom;
+use time::{Date, PrimitiveDateTime, Time};
+
+impl TryFrom<Datetime> for PrimitiveDateTime {
+    type Error = DateTimeParseError;
+
+    /// Fails if the year is outside of the range supported by `time`.
+    fn try_from(value: Datetime) -> Result<Self, Self::Error> {
+        let year = value.date.year.0;
+        let month = time::Month::try_from(value.date.month.0)
+            .expect("The Month type guards against values that aren't in range (1..=12)");
+        let date = Date::from_calendar_date(year, month, value.date.day.0).map_err(|_| {
+            DateTimeParseError {
+                component: Component::Year,
+                found: year.to_string(),
+                kind: DateTimeParseErrorKind::OutOfRange {
+                    min: Date::MIN.year(),
+                    max: Date::MAX.year(),
+                },
+                position: None,
+            }
+        })?;
+
+        let (second, nanos) = value.time.second.whole_and_nanos();
+        let time = Time::from_hms_nano(value.time.hour.0, value.time.minute.0, second, nanos)
+            .expect("The components of a Datetime are in range");
+
+        Ok(PrimitiveDateTime::new(date, time))
+    }
+}
+
+impl TryFrom<PrimitiveDateTime> for Datetime {
+    type Error = DateTimeParseError;
+
+    /// Fails for years before 1, which HTML can't represent. The nanoseconds are truncated to
+    /// milliseconds.
+    fn try_from(value: PrimitiveDateTime) -> Result<Self, Self::Error> {
+        Ok(Datetime {
+            date: YearMonthDay::from_components(
+                Year::try_from(value.year())?,
+                Month(value.month() as u8),
+                Day(value.day()),
+            )?,
+            time: HourMinuteSecond {
+                hour: Hour(value.hour()),
+                minute: Minute(value.minute()),
+                second: Second::from_whole_and_nanos(value.second(), value.nanosecond())?,
+            },
+            separator: Separator::T,
+            expanded_year_digits: None,
+        })
+    }
+}